@@ -0,0 +1,44 @@
+//! Derives a letters table from a wordlist and prints it as JSON, to be checked into the repo.
+//!
+//! ```sh
+//! cargo run --bin letters_table -- wordlists/french1.txt 200 QU > letters/french1.json
+//! ```
+//!
+//! The pool size defaults to the number of letters of a game, it must fill the initial grid and
+//! whole triplets. The following arguments are the multi-character tiles.
+
+use std::{env, fs, process};
+
+use golden_core::{corpus::TileFrequencies, lexicon::NUMBER_OF_LETTERS};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        eprintln!("usage: letters_table <wordlist> [pool size] [multi-character tiles...]");
+        process::exit(2);
+    };
+
+    let pool_size = match args.get(1) {
+        Some(pool_size) => pool_size.parse().unwrap_or_else(|e| {
+            eprintln!("invalid pool size '{pool_size}': {e}");
+            process::exit(2);
+        }),
+        None => NUMBER_OF_LETTERS,
+    };
+    let digraphs: Vec<&str> = args.iter().skip(2).map(String::as_str).collect();
+
+    let wordlist = fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("unable to read '{path}': {e}");
+        process::exit(1);
+    });
+
+    let letters = TileFrequencies::count(&wordlist, &digraphs)
+        .and_then(|frequencies| frequencies.letters(pool_size))
+        .unwrap_or_else(|e| {
+            eprintln!("unable to derive the letters table: {e}");
+            process::exit(1);
+        });
+
+    let json = serde_json::to_string_pretty(&letters).expect("letters are serializable");
+    println!("{json}");
+}
@@ -0,0 +1,117 @@
+use wasm_bindgen::prelude::*;
+
+use crate::scoring::percentage_of;
+
+/// Action of the game that can clear words from the grid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Action {
+    /// A letter moved by the player.
+    Move,
+    /// A letter placed from a triplet.
+    Placement,
+    /// A word traced by the player.
+    Claim,
+}
+
+/// Combo state of the player.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Combo {
+    /// Number of words cleared by the last action that cleared words.
+    pub words: u32,
+    /// Number of consecutive moves, or claims, that each cleared at least one word.
+    pub streak: u32,
+    /// Multiplier applied to the words of the last action, in percent.
+    pub multiplier_percent: u32,
+}
+
+impl Default for Combo {
+    fn default() -> Self {
+        Combo {
+            words: 0,
+            streak: 0,
+            multiplier_percent: 100,
+        }
+    }
+}
+
+impl Combo {
+    /// Updates the combo once an action cleared the given number of words.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - Action that cleared the words.
+    /// * `words` - Number of words cleared by the action.
+    /// * `combo_bonus_percent` - Bonus for each word cleared after the first one.
+    /// * `streak_bonus_percent` - Bonus for each move of the streak after the first one.
+    pub(crate) fn update(
+        &mut self,
+        action: Action,
+        words: u32,
+        combo_bonus_percent: u32,
+        streak_bonus_percent: u32,
+    ) {
+        let by_player = action != Action::Placement;
+        if by_player {
+            self.streak = if words > 0 { self.streak + 1 } else { 0 };
+        }
+
+        if words == 0 {
+            if by_player {
+                self.words = 0;
+                self.multiplier_percent = 100;
+            }
+            return;
+        }
+
+        let streak_bonus = match action {
+            Action::Move | Action::Claim => streak_bonus_percent.saturating_mul(self.streak - 1),
+            Action::Placement => 0,
+        };
+
+        self.words = words;
+        self.multiplier_percent = 100u32
+            .saturating_add(combo_bonus_percent.saturating_mul(words - 1))
+            .saturating_add(streak_bonus);
+    }
+
+    /// Applies the current multiplier to a score.
+    pub(crate) fn apply(&self, score: u32) -> u32 {
+        percentage_of(score, self.multiplier_percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn simultaneous_words_increase_the_multiplier() {
+        let mut combo = Combo::default();
+
+        combo.update(Action::Placement, 3, 50, 10);
+
+        assert_eq!(combo.words, 3);
+        assert_eq!(combo.streak, 0);
+        assert_eq!(combo.multiplier_percent, 200);
+        assert_eq!(combo.apply(30), 60);
+    }
+
+    #[test]
+    fn consecutive_clearing_moves_build_a_streak() {
+        let mut combo = Combo::default();
+
+        combo.update(Action::Move, 1, 50, 10);
+        assert_eq!(combo.multiplier_percent, 100);
+
+        combo.update(Action::Placement, 0, 50, 10);
+        combo.update(Action::Move, 1, 50, 10);
+        combo.update(Action::Move, 2, 50, 10);
+        assert_eq!(combo.streak, 3);
+        assert_eq!(combo.multiplier_percent, 170);
+
+        combo.update(Action::Move, 0, 50, 10);
+        assert_eq!(combo, Combo::default());
+    }
+}
@@ -0,0 +1,287 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    grid::{GridIndex, GridSize},
+    topology::{HexTopology, SquareTopology, Topology},
+};
+
+/// Rules of a game.
+///
+/// Every option defaults to the classic rules of the game.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct GameConfig {
+    /// What to do once every triplet of the letters pool has been placed.
+    pub pool_exhaustion: PoolExhaustionPolicy,
+    /// Maximum play time in milliseconds, the game has no time limit if unset.
+    pub time_limit_ms: Option<u32>,
+    /// Score bonus, in percent, for each extra word cleared by a single action.
+    pub combo_bonus_percent: u32,
+    /// Score bonus, in percent, for each extra consecutive move that clears words.
+    pub streak_bonus_percent: u32,
+    /// How the words removed from the grid are chosen among overlapping ones.
+    pub match_selection: MatchSelection,
+    /// Whether horizontal and vertical words can share the letter where they cross.
+    pub crossword: bool,
+    /// Bonus earned by a word for each letter shared with a crossing word. The bonus is added
+    /// once the words are selected, it does not make crossing words preferred.
+    pub crossing_bonus: u32,
+    /// Directions in which words can be read.
+    pub reading_directions: ReadingDirections,
+    /// Percentage of the score kept by words read backwards, from right to left or from bottom
+    /// to top.
+    pub reversed_word_percent: u32,
+    /// Directions in which letters can move from one cell to another.
+    pub movement_directions: MovementDirections,
+    /// Shape of the words that can be read in the grid.
+    pub word_shape: WordShape,
+    /// Percentage of the score kept by words that are not straight.
+    pub bent_word_percent: u32,
+    /// How the words found in the grid are removed.
+    pub word_claiming: WordClaiming,
+    /// Whether letters above cleared cells fall down, possibly forming new words.
+    pub gravity: bool,
+    /// Score bonus, in percent, for each step of a cascade after the first one.
+    pub cascade_bonus_percent: u32,
+    /// Where the letters of the triplets are placed in the grid.
+    pub letter_placement: LetterPlacement,
+    /// Layout of the board, one line per row where `#` marks a wall, `x` marks a cell removed
+    /// from the board, `d` and `t` mark double and triple letter premiums, `D` and `T` mark
+    /// double and triple word premiums. The board is left as is if unset.
+    #[wasm_bindgen(getter_with_clone)]
+    pub layout: Option<String>,
+    /// Shape of the board, cells outside of it do not exist.
+    pub board_shape: BoardShape,
+    /// Whether moving or reading past one edge of the grid continues on the opposite edge.
+    pub wrap_around: bool,
+    /// Shape of the cells and of their neighbourhood.
+    pub topology: GridTopology,
+    /// Whether the premiums of the board are used up by the first word covering them.
+    pub premium_lifetime: PremiumLifetime,
+    /// Percentage of the letters of the triplets replaced by a wildcard tile.
+    pub wildcard_percent: u32,
+    /// Percentage of the letters of the triplets replaced by a bomb tile.
+    pub bomb_percent: u32,
+    /// Percentage of the letters of the triplets turned into golden tiles.
+    pub golden_tile_percent: u32,
+    /// Number of triplet drops after which a letter left unused turns to stone and can no
+    /// longer be moved, letters stay movable if unset.
+    pub lock_after_drops: Option<u32>,
+    /// Whether the letters turned to stone still form words.
+    pub locked_letters_form_words: bool,
+}
+
+#[wasm_bindgen]
+impl GameConfig {
+    /// Creates a configuration with the classic rules.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> GameConfig {
+        GameConfig::default()
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            pool_exhaustion: PoolExhaustionPolicy::Finish,
+            time_limit_ms: None,
+            combo_bonus_percent: 0,
+            streak_bonus_percent: 0,
+            match_selection: MatchSelection::Greedy,
+            crossword: false,
+            crossing_bonus: 10,
+            reading_directions: ReadingDirections::Orthogonal,
+            reversed_word_percent: 100,
+            movement_directions: MovementDirections::Orthogonal,
+            word_shape: WordShape::Straight,
+            bent_word_percent: 50,
+            word_claiming: WordClaiming::Automatic,
+            gravity: false,
+            cascade_bonus_percent: 50,
+            letter_placement: LetterPlacement::Random,
+            layout: None,
+            board_shape: BoardShape::Rectangle,
+            wrap_around: false,
+            topology: GridTopology::Square,
+            premium_lifetime: PremiumLifetime::Consumed,
+            wildcard_percent: 0,
+            bomb_percent: 0,
+            golden_tile_percent: 0,
+            lock_after_drops: None,
+            locked_letters_form_words: true,
+        }
+    }
+}
+
+/// Policy applied when the triplets of the letters pool are all used.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PoolExhaustionPolicy {
+    /// The game ends.
+    Finish,
+    /// A new shuffled pool is drawn and its triplets are queued.
+    Refill,
+}
+
+/// Strategy used to choose the words removed from the grid when they share letters.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MatchSelection {
+    /// Words with the highest scores are kept first.
+    Greedy,
+    /// Words are chosen to maximize the total score.
+    Optimal,
+}
+
+/// Directions in which words can be read in the grid.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ReadingDirections {
+    /// Only from left to right and from top to bottom, or from left to right along each axis
+    /// of a hexagonal grid.
+    Forward,
+    /// Along rows and columns, or along each axis of a hexagonal grid, in both ways.
+    Orthogonal,
+    /// Along rows, columns and diagonals, in both ways. Same as orthogonal on a hexagonal grid.
+    All,
+}
+
+/// Directions in which letters can move in the grid.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MovementDirections {
+    /// Along rows and columns.
+    Orthogonal,
+    /// Along rows, columns and diagonals. Same as orthogonal on a hexagonal grid.
+    All,
+}
+
+/// Shape of the words that can be read in the grid.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WordShape {
+    /// Words are read along a straight line, following the reading directions.
+    Straight,
+    /// Words follow any chain of orthogonally adjacent letters, each letter being used once.
+    Bent,
+}
+
+/// How the words found in the grid are removed.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum WordClaiming {
+    /// Words are removed as soon as they appear, after each move and letter placement.
+    Automatic,
+    /// Words stay in the grid until the player traces them from their first to their last
+    /// letter, only straight words can be traced.
+    Manual,
+}
+
+/// Strategy used to choose where the letters of the triplets are placed in the grid.
+///
+/// Every strategy picks randomly among its best cells, with the random generator of the game.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LetterPlacement {
+    /// Any empty cell.
+    Random,
+    /// The letter is dropped from the top of the column chosen by the player, or of a random
+    /// column, and stops above the first cell that is not empty.
+    ColumnDrop,
+    /// An empty cell next to a letter.
+    NextToLetters,
+    /// The empty cells closest to the edges of the grid first.
+    EdgesFirst,
+    /// An empty cell where the letter does not complete a word.
+    AvoidWords,
+}
+
+/// Shape of the board, drawn in the rectangle of the grid.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BoardShape {
+    /// Every cell of the grid.
+    Rectangle,
+    /// The middle third of the rows and of the columns.
+    Plus,
+    /// The cells around an empty center.
+    Ring,
+    /// The cells inside a diamond touching the middle of each side.
+    Diamond,
+    /// Regularly spaced holes, away from the edges.
+    Holes,
+}
+
+impl BoardShape {
+    /// Whether the cell at the given column and row is part of the board.
+    pub(crate) fn contains(
+        &self,
+        x: GridIndex,
+        y: GridIndex,
+        width: GridSize,
+        height: GridSize,
+    ) -> bool {
+        let (x, y, width, height) = (x as u32, y as u32, width as u32, height as u32);
+
+        match self {
+            BoardShape::Rectangle => true,
+            BoardShape::Plus => {
+                let in_middle = |i: u32, size: u32| i >= size / 3 && i < size - size / 3;
+                in_middle(x, width) || in_middle(y, height)
+            }
+            BoardShape::Ring => {
+                let thickness = width.min(height).div_ceil(4);
+                x < thickness || y < thickness || x >= width - thickness || y >= height - thickness
+            }
+            BoardShape::Diamond => {
+                // distances to the center, scaled to compare columns and rows
+                let dx = (2 * x).abs_diff(width - 1) * height;
+                let dy = (2 * y).abs_diff(height - 1) * width;
+                dx + dy <= width * height
+            }
+            BoardShape::Holes => {
+                let is_hole = |i: u32, size: u32| i % 3 == 1 && i < size - 1;
+                !(is_hole(x, width) && is_hole(y, height))
+            }
+        }
+    }
+}
+
+/// Shape of the cells of the grid.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum GridTopology {
+    /// Square cells.
+    Square,
+    /// Hexagonal cells, in axial coordinates.
+    Hexagonal,
+}
+
+impl GridTopology {
+    pub(crate) fn topology(&self) -> &'static dyn Topology {
+        match self {
+            GridTopology::Square => &SquareTopology,
+            GridTopology::Hexagonal => &HexTopology,
+        }
+    }
+}
+
+/// Lifetime of the premiums of the board.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PremiumLifetime {
+    /// A premium is used up once a word covering it is removed.
+    Consumed,
+    /// A premium applies to every word covering it.
+    Permanent,
+}
@@ -0,0 +1,227 @@
+use std::cmp::Reverse;
+
+use itertools::Itertools;
+
+use crate::lexicon::{LetterConfig, LexiconError, Tokenizer, Word, check_pool_size};
+
+/// Highest score of a tile, given to the rarest one, as in the french letters table.
+pub const MAX_LETTER_SCORE: u32 = 9;
+
+/// Number of occurrences of each tile in the playable words of a wordlist.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TileFrequencies {
+    /// Tiles found in the playable words, sorted, with their number of occurrences.
+    tiles: Vec<(String, u64)>,
+}
+
+impl TileFrequencies {
+    /// Counts the tiles of a wordlist holding a word per line. The tiles are the characters of
+    /// the wordlist and the given multi-character tiles, words being split as the letters table
+    /// does. Words too short or too long to be played, or holding other characters than letters,
+    /// are ignored.
+    pub fn count(wordlist: &str, digraphs: &[&str]) -> Result<TileFrequencies, LexiconError> {
+        let words: Vec<&str> = wordlist
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && line.chars().all(char::is_alphabetic))
+            .collect();
+
+        let tiles: Vec<String> = words
+            .iter()
+            .flat_map(|word| word.chars().map(String::from))
+            .chain(digraphs.iter().map(|digraph| digraph.to_string()))
+            .sorted()
+            .dedup()
+            .collect();
+        let tokenizer = Tokenizer::new(tiles.iter().map(String::as_str))?;
+
+        let mut occurrences = vec![0u64; tiles.len()];
+        for word in words {
+            let Ok(indexes) = tokenizer.tokenize(word) else {
+                continue;
+            };
+            if !(Word::MIN_LENGTH..=Word::MAX_LENGTH).contains(&indexes.len()) {
+                continue;
+            }
+
+            for index in indexes {
+                occurrences[index.0 as usize] += 1;
+            }
+        }
+
+        // a character only found within digraphs is not a tile on its own
+        let tiles = tiles
+            .into_iter()
+            .zip(occurrences)
+            .filter(|(_, occurrences)| *occurrences > 0)
+            .collect();
+
+        Ok(TileFrequencies { tiles })
+    }
+
+    /// Letters of a table drawing pools of `pool_size` letters. Each tile is drawn at least
+    /// once, the rest of the pool being shared along the frequencies of the tiles. Scores go
+    /// from 1 for the most frequent tile to `MAX_LETTER_SCORE` for the rarest one.
+    pub fn letters(&self, pool_size: usize) -> Result<Vec<LetterConfig>, LexiconError> {
+        if self.tiles.is_empty() {
+            return Err(LexiconError::EmptyCorpus);
+        }
+        check_pool_size(pool_size, self.tiles.len())?;
+
+        let letters = self
+            .tiles
+            .iter()
+            .zip(self.repartitions(pool_size))
+            .zip(self.scores())
+            .map(|(((tile, _), repartition), score)| LetterConfig {
+                letter: tile.clone(),
+                repartition,
+                score,
+            })
+            .collect();
+
+        Ok(letters)
+    }
+
+    /// Shares the pool with the largest remainder method, once each tile got a letter.
+    fn repartitions(&self, pool_size: usize) -> Vec<usize> {
+        let total: u64 = self.tiles.iter().map(|(_, occurrences)| occurrences).sum();
+        let shared = (pool_size - self.tiles.len()) as u64;
+
+        let shares: Vec<(u64, u64)> = self
+            .tiles
+            .iter()
+            .map(|(_, occurrences)| {
+                let share = occurrences * shared;
+                (share / total, share % total)
+            })
+            .collect();
+
+        let mut repartitions: Vec<usize> = shares
+            .iter()
+            .map(|(quotient, _)| 1 + *quotient as usize)
+            .collect();
+
+        // letters lost to the rounding go to the largest remainders
+        let missing = pool_size - repartitions.iter().sum::<usize>();
+        let by_remainder = (0..shares.len()).sorted_by_key(|&i| (Reverse(shares[i].1), i));
+        for i in by_remainder.take(missing) {
+            repartitions[i] += 1;
+        }
+
+        repartitions
+    }
+
+    /// Scores following the logarithm of the rarity of the tiles.
+    fn scores(&self) -> Vec<u32> {
+        let log = |occurrences: u64| (occurrences as f64).ln();
+        let most = self.tiles.iter().map(|(_, o)| *o).max().map_or(0.0, log);
+        let least = self.tiles.iter().map(|(_, o)| *o).min().map_or(0.0, log);
+
+        self.tiles
+            .iter()
+            .map(|(_, occurrences)| {
+                if most == least {
+                    return 1;
+                }
+
+                let rarity = (most - log(*occurrences)) / (most - least);
+                1 + (rarity * (MAX_LETTER_SCORE - 1) as f64).round() as u32
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::lexicon::{LettersTable, NUMBER_OF_LETTERS};
+
+    const WORDLIST: &str = "QUILLE\nQUELLE\nBELLE\nSEL\nLISSEE\nL'EST\n";
+
+    #[test]
+    fn tiles_of_the_playable_words_are_counted() -> Result<(), LexiconError> {
+        let frequencies = TileFrequencies::count(WORDLIST, &["QU"])?;
+
+        // SEL is too short, L'EST is not a word, no Q stands without its U
+        assert_eq!(
+            frequencies.tiles,
+            ["B", "E", "I", "L", "QU", "S"]
+                .into_iter()
+                .map(String::from)
+                .zip([1, 7, 2, 7, 2, 2])
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn letters_fill_the_pool_and_score_the_rarest_tiles_most() -> Result<(), LexiconError> {
+        let letters = TileFrequencies::count(WORDLIST, &["QU"])?.letters(NUMBER_OF_LETTERS)?;
+
+        assert_eq!(
+            letters.iter().map(|lc| lc.repartition).sum::<usize>(),
+            NUMBER_OF_LETTERS
+        );
+        let score_of = |tile: &str| letters.iter().find(|lc| lc.letter == tile).unwrap().score;
+        assert_eq!(score_of("E"), 1);
+        assert_eq!(score_of("L"), 1);
+        assert_eq!(score_of("B"), MAX_LETTER_SCORE);
+        assert!(score_of("QU") < score_of("B"));
+
+        assert_eq!(
+            TileFrequencies::count("L'EST\nSEL\n", &[])?.letters(NUMBER_OF_LETTERS),
+            Err(LexiconError::EmptyCorpus)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pools_of_any_size_fill_the_initial_grid_and_whole_triplets() -> Result<(), LexiconError> {
+        // 8 letters of the initial grid and a triplet
+        let letters = TileFrequencies::count(WORDLIST, &["QU"])?.letters(11)?;
+        let table = LettersTable::new(letters, 11)?;
+
+        let pool = table.generate_letters_pool(&mut ChaCha8Rng::seed_from_u64(42));
+        assert_eq!(pool.initial_grid_selection.len(), 8);
+        assert_eq!(pool.triplets.len(), 1);
+
+        assert_eq!(
+            TileFrequencies::count(WORDLIST, &["QU"])?.letters(12),
+            Err(LexiconError::InvalidPoolSize { pool_size: 12 })
+        );
+        assert_eq!(
+            TileFrequencies::count("ABCDEF\nGHIJKL\n", &[])?.letters(11),
+            Err(LexiconError::PoolTooSmall {
+                pool_size: 11,
+                tiles: 12
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialized_letters_are_read_back_as_a_table() -> Result<(), LexiconError> {
+        let letters = TileFrequencies::count(WORDLIST, &["QU"])?.letters(NUMBER_OF_LETTERS)?;
+        let json = serde_json::to_string_pretty(&letters).unwrap();
+
+        let table = LettersTable::from_json(&json, NUMBER_OF_LETTERS)?;
+
+        assert_eq!(table.parse_word("QUELLE")?.length(), 5);
+        let derived =
+            LettersTable::from_json(include_str!("../letters/french1.json"), NUMBER_OF_LETTERS)?;
+        assert_eq!(derived.parse_word("QUITTE")?.length(), 5);
+        assert!(matches!(
+            LettersTable::from_json("[]", NUMBER_OF_LETTERS),
+            Err(LexiconError::UnexpectedNumberOfLettersInLettersTable { .. })
+        ));
+
+        Ok(())
+    }
+}
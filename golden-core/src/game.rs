@@ -1,3 +1,4 @@
+use itertools::Itertools;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
@@ -5,11 +6,20 @@ use wasm_bindgen::prelude::*;
 
 use crate::{
     clock::Clock,
+    combo::{Action, Combo},
+    config::{BoardShape, GameConfig, GridTopology, PoolExhaustionPolicy, WordClaiming},
     debug,
-    grid::{self, Grid, GridSize, MoveResult, Position},
+    grid::{
+        self, ClaimError, Direction, Grid, GridIndex, GridSize, Match, MoveResult, Position,
+        WordSearch,
+    },
     lexicon::{
-        FRENCH_DICTIONARY, FRENCH_LETTERS_TABLE, LETTER_INDEX_NONE, LetterIndex, LettersTable, Word,
+        FRENCH_DICTIONARY, FRENCH_LETTERS_TABLE, LETTER_INDEX_BOMB, LETTER_INDEX_NONE,
+        LETTER_INDEX_WILDCARD, LetterIndex, LettersTable, TileKind, Word,
     },
+    placement::choose_position,
+    scoring::{ClassicScoring, Premium, ScoringRule, percentage_of},
+    summary::GameSummary,
 };
 
 enum GridStatus {
@@ -22,26 +32,46 @@ enum GridStatus {
 pub struct Game {
     /// Current state of the game.
     pub state: GameState,
+    end_reason: Option<EndReason>,
+    config: GameConfig,
     /// Maximum clock time in milliseconds.
     pub clock_max_ms: u32,
     /// Grid width.
     pub grid_width: GridSize,
     /// Grid height.
     pub grid_height: GridSize,
+    /// Shape of the cells of the grid, for the grid snapshot to be drawn.
+    pub topology: GridTopology,
     clock: Clock,
     grid: Grid,
     rng: ChaCha8Rng,
     golden_word: Word,
     /// Score of the golden word.
-    pub golden_word_score: u16,
+    pub golden_word_score: u32,
     /// Current index in the triplets list.
-    pub triplets_current_index: u8,
+    pub triplets_current_index: u32,
     triplets: Vec<(u8, u8, u8)>,
     /// Current score of the player.
-    pub score: u16,
+    pub score: u32,
     found_words: Vec<FoundWord>,
+    /// Number of moves done by the player.
+    pub moves: u32,
+    /// Time spent playing in milliseconds.
+    pub play_time_ms: u32,
+    /// Number of ticks processed since the start of the game.
+    pub ticks: u32,
+    combo: Combo,
+    /// Cells shared by crossing words during the last removal of words.
+    shared_cells: Vec<Position>,
+    /// Steps of the last cascade of cleared words.
+    cascade: Vec<CascadeStep>,
+    /// Why the last word traced by the player could not be claimed.
+    claim_error: Option<ClaimError>,
     path_from: Option<Position>,
     path_to: Option<Position>,
+    /// Column chosen by the player to drop the next letters.
+    drop_column: Option<GridIndex>,
+    scoring: Box<dyn ScoringRule>,
 }
 
 #[wasm_bindgen]
@@ -56,41 +86,76 @@ impl Game {
     /// * `seed` - Seed for random generation.
     #[wasm_bindgen(constructor)]
     pub fn new(clock_ms: u32, grid_width: GridSize, grid_height: GridSize, seed: u32) -> Game {
+        Game::with_config(
+            clock_ms,
+            grid_width,
+            grid_height,
+            seed,
+            GameConfig::default(),
+        )
+    }
+
+    /// Creates a new game instance following the given rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock_ms` - Initial clock time in milliseconds.
+    /// * `grid_width` - Width of the grid.
+    /// * `grid_height` - Height of the grid.
+    /// * `seed` - Seed for random generation.
+    /// * `config` - Rules of the game.
+    pub fn with_config(
+        clock_ms: u32,
+        grid_width: GridSize,
+        grid_height: GridSize,
+        seed: u32,
+        config: GameConfig,
+    ) -> Game {
         debug!("init game");
         debug!("  clock_ms: {}", clock_ms);
         debug!("  grid_width: {}", grid_width);
         debug!("  grid_height: {}", grid_height);
         debug!("  seed: {}", seed);
+        debug!("  config: {:?}", config);
 
         let mut rng = rng_from_u32(seed);
 
         let clock = Clock::new(clock_ms);
         let pool = FRENCH_LETTERS_TABLE.generate_letters_pool(&mut rng);
         let golden_word = FRENCH_DICTIONARY.get_random_six_letter_word(&mut rng);
-        let golden_word_score = golden_word
-            .score(&FRENCH_LETTERS_TABLE, &golden_word)
-            .expect("unable to calculate score of golden word")
-            as u16;
+        let scoring: Box<dyn ScoringRule> = Box::new(ClassicScoring);
+        let golden_word_score = golden_word_score(scoring.as_ref(), &golden_word);
 
-        let grid = generate_initial_grid(
+        let mut grid = generate_initial_grid(
             grid_width,
             grid_height,
+            config.board_shape,
+            config.layout.as_deref(),
             pool.initial_grid_selection,
             &mut rng,
         );
+        grid.set_movement_directions(config.movement_directions);
+        grid.set_wrap_around(config.wrap_around);
+        grid.set_topology(config.topology.topology());
+        grid.set_premium_lifetime(config.premium_lifetime);
+        grid.set_lock_age(config.lock_after_drops, config.locked_letters_form_words);
 
-        let triplets = pool
+        let mut triplets: Vec<(u8, u8, u8)> = pool
             .triplets
             .into_iter()
             .map(|t| (t.0.into(), t.1.into(), t.2.into()))
             .collect();
+        add_special_tiles(&mut triplets, &config, &mut rng);
 
         Game {
             state: GameState::OnGoing,
+            end_reason: None,
             rng,
             clock_max_ms: clock_ms,
             grid_width,
             grid_height,
+            topology: config.topology,
+            config,
             clock,
             grid,
             golden_word,
@@ -99,8 +164,17 @@ impl Game {
             triplets_current_index: 0,
             score: 0,
             found_words: vec![],
+            moves: 0,
+            play_time_ms: 0,
+            ticks: 0,
+            combo: Combo::default(),
+            shared_cells: vec![],
+            cascade: vec![],
+            claim_error: None,
+            drop_column: None,
             path_from: None,
             path_to: None,
+            scoring,
         }
     }
 
@@ -109,7 +183,9 @@ impl Game {
     /// # Arguments
     ///
     /// * `delta_ms` - Time delta in milliseconds.
-    /// * `clicks_on_cell` - List of cell positions (x, y) that were clicked during this tick.
+    /// * `clicks_on_cell` - List of cell positions (x, y) that were clicked during this tick. When
+    ///   words are claimed manually, clicking a letter then another one claims the word between
+    ///   them, clicking an empty cell moves the letter.
     /// * `last_hovered_cell` - Optional position (x, y) of the last hovered cell.
     pub fn tick(
         &mut self,
@@ -121,15 +197,30 @@ impl Game {
             return self.generate_game_snapshot();
         }
 
+        self.ticks += 1;
+        self.play_time_ms = self.play_time_ms.saturating_add(delta_ms);
+        if let Some(time_limit_ms) = self.config.time_limit_ms
+            && self.play_time_ms >= time_limit_ms
+        {
+            self.finish_game(EndReason::TimeLimit);
+            return self.generate_game_snapshot();
+        }
+
         self.update_clock(delta_ms);
 
         if self.clock.remaining_ms() == 0 {
-            if let GridStatus::Full = self.place_new_triplets_in_grid() {
+            self.place_new_triplets_in_grid();
+            if self.state == GameState::Finished {
                 return self.generate_game_snapshot();
             }
         }
 
         for pos in clicks_on_cell {
+            // cells removed by the board shape cannot be clicked
+            if !self.grid.cells().contains_key(&pos) {
+                continue;
+            }
+
             match self.path_from {
                 Some(from_pos) => {
                     // Unselect the from position if clicked again
@@ -138,19 +229,35 @@ impl Game {
                         continue;
                     }
 
+                    if self.config.word_claiming == WordClaiming::Manual
+                        && let grid::Cell::Letter(_) = self.grid.cell(&pos)
+                    {
+                        self.path_from = None;
+                        self.path_to = None;
+                        self.claim_error = self.claim(from_pos, pos).err();
+                        continue;
+                    }
+
                     let moved = self.grid.move_cell(pos, from_pos);
                     if moved == MoveResult::Moved {
+                        self.moves += 1;
                         self.path_from = None;
                         self.path_to = None;
-                        self.remove_found_words_in_grid();
-                        if let GridStatus::Full = self.place_new_triplets_in_grid() {
+                        self.explode_bomb_at(pos);
+                        self.remove_found_words_in_grid(Action::Move);
+                        self.place_new_triplets_in_grid();
+                        if self.state == GameState::Finished {
                             return self.generate_game_snapshot();
                         }
                     }
                 }
                 None => {
-                    // Start a new path if the clicked cell contains a letter
-                    if let grid::Cell::Letter(_) = self.grid.cell(&pos) {
+                    // Start a new path if the clicked cell contains a movable letter, or the
+                    // first letter of a word to claim
+                    if let grid::Cell::Letter(_) = self.grid.cell(&pos)
+                        && (!self.grid.is_locked(&pos)
+                            || self.config.word_claiming == WordClaiming::Manual)
+                    {
                         self.path_from = Some(pos);
                     }
                 }
@@ -158,7 +265,9 @@ impl Game {
         }
 
         if let Some(_path_from) = self.path_from {
-            if let Some(hovered) = last_hovered_cell {
+            if let Some(hovered) =
+                last_hovered_cell.filter(|pos| self.grid.cells().contains_key(pos))
+            {
                 self.path_to = Some(hovered);
             }
         } else {
@@ -168,21 +277,61 @@ impl Game {
         self.generate_game_snapshot()
     }
 
-    fn place_new_triplets_in_grid(&mut self) -> GridStatus {
+    /// Places the next triplet in the grid, finishing the game if it is not possible.
+    fn place_new_triplets_in_grid(&mut self) {
+        if self.triplets_current_index as usize >= self.triplets.len() {
+            match self.config.pool_exhaustion {
+                PoolExhaustionPolicy::Finish => {
+                    self.finish_game(EndReason::PoolExhausted);
+                    return;
+                }
+                PoolExhaustionPolicy::Refill => self.refill_triplets(),
+            }
+        }
+
         let Some(triplet) = self.pop_triplet() else {
-            // TODO handle no more triplets case
-            return GridStatus::NotFull;
+            self.finish_game(EndReason::PoolExhausted);
+            return;
         };
 
+        self.grid.age_letters();
+
         if let GridStatus::Full = self.try_place_triplet(triplet) {
             // Grid is full, cannot place triplet
-            self.finish_game();
-            return GridStatus::Full;
+            self.finish_game(EndReason::GridFull);
+            return;
         }
 
         self.clock.reset();
+    }
 
-        GridStatus::NotFull
+    /// Queues the triplets of a newly shuffled letters pool.
+    fn refill_triplets(&mut self) {
+        debug!("refill triplets");
+
+        let pool = FRENCH_LETTERS_TABLE.generate_letters_pool(&mut self.rng);
+        let mut triplets: Vec<(u8, u8, u8)> = pool
+            .triplets
+            .into_iter()
+            .map(|t| (t.0.into(), t.1.into(), t.2.into()))
+            .collect();
+        add_special_tiles(&mut triplets, &self.config, &mut self.rng);
+        self.triplets.extend(triplets);
+    }
+
+    /// Clears the neighbourhood of the moved tile if it is a bomb.
+    fn explode_bomb_at(&mut self, pos: Position) {
+        let grid::Cell::Letter(letter) = self.grid.cell(&pos) else {
+            return;
+        };
+        if letter.tile_kind() != TileKind::Bomb {
+            return;
+        }
+
+        let cleared = self.grid.explode(pos);
+        if self.config.gravity {
+            self.grid.apply_gravity(&cleared);
+        }
     }
 
     /// Attempts to place a triplet on the grid.
@@ -194,28 +343,114 @@ impl Game {
             LetterIndex::from(l3),
         ];
 
+        // the combo is earned by the whole triplet, not letter by letter
+        let mut steps: Vec<Vec<Match>> = Vec::new();
+        let mut status = GridStatus::NotFull;
         for &letter in &letters {
             if !self.try_place_letter(letter) {
-                return GridStatus::Full;
+                status = GridStatus::Full;
+                break;
             }
 
-            self.remove_found_words_in_grid();
+            for (step, matches) in self.clear_found_words().into_iter().enumerate() {
+                match steps.get_mut(step) {
+                    Some(found) => found.extend(matches),
+                    None => steps.push(matches),
+                }
+            }
+        }
+
+        if self.config.word_claiming != WordClaiming::Manual {
+            self.record_steps(Action::Placement, steps);
         }
 
-        GridStatus::NotFull
+        status
     }
 
     /// Attempts to place a single letter on the grid.
     /// Returns true if successful, false if the grid is full.
     fn try_place_letter(&mut self, letter: LetterIndex) -> bool {
-        self.place_letter_in_random_empty_cell(letter).is_some()
+        self.place_letter(letter).is_some()
     }
 
-    fn remove_found_words_in_grid(&mut self) {
-        let matches =
-            self.grid
-                .retrieve_words(&FRENCH_DICTIONARY, &FRENCH_LETTERS_TABLE, &self.golden_word);
+    fn remove_found_words_in_grid(&mut self, action: Action) {
+        // claimed words stay in the grid until the player traces them
+        if self.config.word_claiming == WordClaiming::Manual {
+            return;
+        }
+
+        let steps = self.clear_found_words();
+        self.record_steps(action, steps);
+    }
+
+    /// Removes the words found in the grid, then those formed by the fallen letters, without
+    /// scoring them. Returns the words removed at each step of the cascade.
+    fn clear_found_words(&mut self) -> Vec<Vec<Match>> {
+        if self.config.word_claiming == WordClaiming::Manual {
+            return Vec::new();
+        }
 
+        let mut steps = Vec::new();
+        loop {
+            let search = word_search(&self.config, &self.golden_word, self.scoring.as_ref());
+            let matches = self.grid.retrieve_words(&search);
+            let cleared: Vec<Position> = matches
+                .iter()
+                .flat_map(|m| m.positions.iter().copied())
+                .collect();
+
+            steps.push(matches);
+
+            if !self.config.gravity || !self.grid.apply_gravity(&cleared) {
+                break;
+            }
+
+            // the selected letter may have fallen
+            self.path_from = None;
+        }
+
+        steps
+    }
+
+    /// Scores the words removed by an action and by each step of the cascade that followed it.
+    fn record_steps(&mut self, action: Action, steps: Vec<Vec<Match>>) {
+        if steps.is_empty() {
+            // the action cleared no word, which still counts for the combo
+            self.record_matches(action, Vec::new(), 0);
+        }
+
+        for (step, matches) in steps.into_iter().enumerate() {
+            self.record_matches(action, matches, step as u32);
+        }
+    }
+
+    /// Claims the straight word traced by the player from its first to its last letter.
+    ///
+    /// Only available when words are claimed manually.
+    pub fn claim(&mut self, start: Position, end: Position) -> Result<FoundWord, ClaimError> {
+        if self.state == GameState::Finished || self.config.word_claiming != WordClaiming::Manual {
+            return Err(ClaimError::NotAllowed);
+        }
+
+        let search = word_search(&self.config, &self.golden_word, self.scoring.as_ref());
+        let claimed = self.grid.claim_word(start, end, &search)?;
+        let cleared = claimed.positions.clone();
+        self.record_matches(Action::Claim, vec![claimed], 0);
+
+        if self.config.gravity && self.grid.apply_gravity(&cleared) {
+            self.path_from = None;
+        }
+
+        Ok(self
+            .found_words
+            .last()
+            .expect("the claimed word has just been recorded")
+            .clone())
+    }
+
+    /// Scores the words removed from the grid by an action, or by the given step of the cascade
+    /// that followed it.
+    fn record_matches(&mut self, action: Action, matches: Vec<Match>, step: u32) {
         // If the current path_from position is part of a found word, unselect it
         if let Some(pos) = self.path_from {
             if matches
@@ -227,14 +462,45 @@ impl Game {
             }
         }
 
+        // the shared cells are those of the last action and of its cascade
+        if step == 0 {
+            self.shared_cells.clear();
+        }
+        self.shared_cells.extend(
+            matches
+                .iter()
+                .flat_map(|m| m.positions.iter())
+                .duplicates()
+                .copied(),
+        );
+
+        // the combo is earned by the action, the cascade multiplies it
+        if step == 0 {
+            self.combo.update(
+                action,
+                matches.len() as u32,
+                self.config.combo_bonus_percent,
+                self.config.streak_bonus_percent,
+            );
+        }
+
+        let cascade_multiplier_percent =
+            100u32.saturating_add(self.config.cascade_bonus_percent.saturating_mul(step));
+        if !matches.is_empty() {
+            if step == 0 {
+                self.cascade.clear();
+            }
+            self.cascade.push(CascadeStep {
+                step,
+                words: matches.len() as u32,
+                multiplier_percent: cascade_multiplier_percent,
+            });
+        }
+
         let found_words: Vec<FoundWord> = matches
             .iter()
             .map(|m| {
-                let score = m
-                    .word
-                    .score(&FRENCH_LETTERS_TABLE, &self.golden_word)
-                    .expect("unable to calculate score of word in the grid")
-                    as u16;
+                let breakdown = m.breakdown.clone();
 
                 let word: String = m
                     .word
@@ -245,28 +511,95 @@ impl Game {
                             .try_get_letter(LetterIndex::from(l))
                             .unwrap()
                             .letter
+                            .as_str()
                     })
                     .collect();
 
-                FoundWord { word, score }
+                FoundWord {
+                    word,
+                    length: m.word.length(),
+                    score: percentage_of(
+                        self.combo.apply(breakdown.total),
+                        cascade_multiplier_percent,
+                    ),
+                    golden_word: m.word == self.golden_word,
+                    positions: m.positions.clone(),
+                    direction: m.direction,
+                    letter_scores: breakdown.letter_scores,
+                    letter_multipliers: breakdown.letter_multipliers,
+                    length_multiplier: breakdown.length_multiplier,
+                    word_multiplier: breakdown.word_multiplier,
+                    golden_bonus: breakdown.golden_bonus,
+                    crossing_bonus: breakdown.crossing_bonus,
+                    reversed_percent: breakdown.reversed_percent,
+                    bent_percent: breakdown.bent_percent,
+                    combo_multiplier_percent: self.combo.multiplier_percent,
+                    cascade_step: step,
+                    cascade_multiplier_percent,
+                    found_at_tick: self.ticks,
+                    found_at_move: self.moves,
+                }
             })
             .collect();
 
-        let total_score_found_words: u16 = found_words.iter().map(|w| w.score).sum();
+        let total_score_found_words = found_words
+            .iter()
+            .fold(0u32, |total, w| total.saturating_add(w.score));
 
         self.found_words.extend(found_words);
-        self.score += total_score_found_words;
+        self.score = self.score.saturating_add(total_score_found_words);
     }
 
     /// Marks the game as finished.
-    fn finish_game(&mut self) {
+    fn finish_game(&mut self, reason: EndReason) {
+        debug!("game finished: {:?}", reason);
         self.state = GameState::Finished;
+        self.end_reason = Some(reason);
+    }
+
+    /// Reason why the game ended, if it is finished.
+    pub fn end_reason(&self) -> Option<EndReason> {
+        self.end_reason
+    }
+
+    /// Chooses the column where the next letters are dropped, when letters are dropped in
+    /// columns.
+    pub fn set_drop_column(&mut self, column: Option<GridIndex>) {
+        self.drop_column = column;
+    }
+
+    /// Ends the game on behalf of the player.
+    pub fn abandon(&mut self) {
+        if self.state == GameState::OnGoing {
+            self.finish_game(EndReason::Abandoned);
+        }
+    }
+
+    /// Summary of the game, available once the game is finished.
+    pub fn summary(&self) -> Option<GameSummary> {
+        let reason = self.end_reason?;
+
+        Some(GameSummary::new(
+            reason,
+            self.score,
+            self.moves,
+            &self.found_words,
+            self.play_time_ms,
+            self.triplets_current_index,
+        ))
     }
 
     fn generate_game_snapshot(&self) -> GameSnapshot {
         GameSnapshot {
             clock_remaining_ms: self.clock.remaining_ms(),
             grid: self.generate_grid_snapshot(),
+            board_mask: self.generate_board_mask(),
+            path_preview: self.generate_path_preview(),
+            combo: self.combo,
+            topology: self.topology,
+            shared_cells: self.shared_cells.clone(),
+            cascade: self.cascade.clone(),
+            claim_error: self.claim_error,
             found_words: self.found_words.clone(),
         }
     }
@@ -275,19 +608,55 @@ impl Game {
         self.clock.substract(delta_ms);
     }
 
+    /// Path currently drawn by the player, if any.
+    fn current_path(&self) -> Option<Vec<Position>> {
+        match (self.path_from, self.path_to) {
+            (Some(from), Some(to)) => self.grid.most_direct_path(&from, &to),
+            (Some(from), None) => Some(vec![from]),
+            _ => None,
+        }
+    }
+
+    fn generate_path_preview(&self) -> Option<PathPreview> {
+        let positions = self.current_path()?;
+
+        let directions = positions
+            .iter()
+            .tuple_windows()
+            .map(|(from, to)| {
+                self.grid
+                    .direction_between(from, to)
+                    .expect("path positions must be adjacent")
+            })
+            .collect();
+
+        Some(PathPreview {
+            positions,
+            directions,
+        })
+    }
+
+    /// Whether each cell of the grid belongs to the board, row by row.
+    fn generate_board_mask(&self) -> Vec<u8> {
+        (0..self.grid.height())
+            .flat_map(|y| (0..self.grid.width()).map(move |x| Position::new(x, y)))
+            .map(|pos| u8::from(self.grid.cells().contains_key(&pos)))
+            .collect()
+    }
+
+    /// Cells of the board, the cells outside of its shape are missing.
     fn generate_grid_snapshot(&self) -> Vec<Cell> {
-        let (allowed_moving_positions, current_path) = match (self.path_from, self.path_to) {
-            (Some(from), Some(to)) => {
-                let allowed_moving_positions = self.grid.allowed_moving_positions(from);
-                let path = self.grid.most_direct_path(&from, &to);
-                (Some(allowed_moving_positions), path)
-            }
-            (Some(from), None) => {
-                let allowed_moving_positions = self.grid.allowed_moving_positions(from);
-                (Some(allowed_moving_positions), Some(vec![from]))
-            }
-            _ => (None, None),
-        };
+        let allowed_moving_positions = self
+            .path_from
+            .map(|from| self.grid.allowed_moving_positions(from));
+        let current_path = self.current_path();
+        let topology = self.topology.topology();
+
+        // start and end of the path exchange their content once the move is done
+        let previewed_move = current_path
+            .as_ref()
+            .filter(|path| path.len() > 1)
+            .map(|path| (*path.first().unwrap(), *path.last().unwrap()));
 
         self.grid
             .cells()
@@ -312,55 +681,56 @@ impl Game {
                     CellPathingStatus::None
                 };
 
-                // exchange start and end positions in the current path
-                let position = {
-                    if let Some(current_path) = current_path.clone() {
-                        let start = *current_path.first().unwrap();
-                        let end = *current_path.last().unwrap();
-
-                        if pos == start {
-                            end
-                        } else if pos == end {
-                            start
-                        } else {
-                            pos
-                        }
-                    } else {
-                        pos
-                    }
+                let preview_position = match previewed_move {
+                    Some((start, end)) if pos == start => Some(end),
+                    Some((start, end)) if pos == end => Some(start),
+                    _ => None,
                 };
 
+                let (center_x, center_y) = topology.center(&preview_position.unwrap_or(pos));
+
                 Cell {
-                    position,
-                    pathing_status: pathing_status,
+                    position: pos,
+                    preview_position,
+                    center_x,
+                    center_y,
+                    pathing_status,
                     letter: match c {
                         grid::Cell::Letter(index) => index.into(),
-                        grid::Cell::Empty => LETTER_INDEX_NONE.0,
+                        grid::Cell::Empty | grid::Cell::Wall => LETTER_INDEX_NONE.0,
                     },
+                    wall: c == grid::Cell::Wall,
+                    premium: self.grid.premium(&pos),
+                    age: self.grid.age(&pos),
+                    locked: self.grid.is_locked(&pos),
                 }
             })
             .collect()
     }
 
-    fn place_letter_in_random_empty_cell(&mut self, letter_index: LetterIndex) -> Option<Position> {
-        if let Some(pos) = self.grid.random_empty_cell_position(&mut self.rng) {
-            self.grid.update_cell(pos, grid::Cell::Letter(letter_index));
-            return Some(pos);
-        }
-
-        None
+    fn place_letter(&mut self, letter_index: LetterIndex) -> Option<Position> {
+        let search = word_search(&self.config, &self.golden_word, self.scoring.as_ref());
+        let pos = choose_position(
+            self.config.letter_placement,
+            &self.grid,
+            letter_index,
+            self.drop_column,
+            &search,
+            &mut self.rng,
+        )?;
+
+        self.grid.update_cell(pos, grid::Cell::Letter(letter_index));
+        Some(pos)
     }
 
-    /// Gets the letter score for the given letter index in the letters table.
-    pub fn get_letter_score(&self, index: u8) -> u32 {
-        let index = LetterIndex::try_from(index).expect("Invalid letter index value");
-        FRENCH_LETTERS_TABLE.try_get_letter(index).unwrap().score
+    /// Gets the letter score for the given tile, `None` for special tiles.
+    pub fn get_letter_score(&self, index: u8) -> Option<u32> {
+        FRENCH_LETTERS_TABLE.try_get_letter_score(index)
     }
 
-    /// Gets the letter for the given letter index in the letters table.
-    pub fn get_letter(&self, index: u8) -> char {
-        let index = LetterIndex::try_from(index).expect("Invalid letter index value");
-        FRENCH_LETTERS_TABLE.try_get_letter(index).unwrap().letter
+    /// Gets the letter for the given tile, `None` for special tiles.
+    pub fn get_letter(&self, index: u8) -> Option<String> {
+        FRENCH_LETTERS_TABLE.try_get_letter_char(index)
     }
 
     pub fn get_letters_table(&self) -> LettersTable {
@@ -390,6 +760,15 @@ impl Game {
     }
 }
 
+/// Extension points of the game that are not exposed through WebAssembly.
+impl Game {
+    /// Replaces the rule used to score the words found in the grid.
+    pub fn set_scoring_rule(&mut self, scoring: Box<dyn ScoringRule>) {
+        self.golden_word_score = golden_word_score(scoring.as_ref(), &self.golden_word);
+        self.scoring = scoring;
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Copy, Clone, PartialEq, Eq)]
 #[repr(u8)]
@@ -398,16 +777,37 @@ pub enum GameState {
     Finished,
 }
 
+/// Reason why a game ended.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EndReason {
+    /// A new letter could not be placed because the grid is full.
+    GridFull,
+    /// Every triplet of the letters pool has been placed.
+    PoolExhausted,
+    /// The time limit of the game has been reached.
+    TimeLimit,
+    /// The player gave up.
+    Abandoned,
+}
+
 fn generate_initial_grid<R>(
     grid_width: GridSize,
     grid_height: GridSize,
+    shape: BoardShape,
+    layout: Option<&str>,
     initial_letters: Vec<LetterIndex>,
     rng: &mut R,
 ) -> Grid
 where
     R: Rng + ?Sized,
 {
-    let grid = Grid::empty(grid_width, grid_height);
+    let mut grid = Grid::empty(grid_width, grid_height);
+    grid.apply_shape(shape);
+    if let Some(layout) = layout {
+        grid.apply_layout(layout);
+    }
 
     // place initial letters randomly in the grid
     let grid = initial_letters
@@ -424,6 +824,62 @@ where
     grid
 }
 
+/// Replaces letters of the triplets by special tiles, at the rates of the configuration.
+fn add_special_tiles<R>(triplets: &mut [(u8, u8, u8)], config: &GameConfig, rng: &mut R)
+where
+    R: Rng + ?Sized,
+{
+    let wildcard = config.wildcard_percent;
+    let bomb = wildcard.saturating_add(config.bomb_percent);
+    let golden = bomb.saturating_add(config.golden_tile_percent);
+    // the random draws of classic games stay the same for a given seed
+    if golden == 0 {
+        return;
+    }
+
+    for (l1, l2, l3) in triplets.iter_mut() {
+        for letter in [l1, l2, l3] {
+            let roll = rng.random_range(0..100);
+            *letter = if roll < wildcard {
+                LETTER_INDEX_WILDCARD.0
+            } else if roll < bomb {
+                LETTER_INDEX_BOMB.0
+            } else if roll < golden {
+                LetterIndex::from(*letter).golden().0
+            } else {
+                *letter
+            };
+        }
+    }
+}
+
+fn word_search<'a>(
+    config: &GameConfig,
+    golden_word: &'a Word,
+    scoring: &'a dyn ScoringRule,
+) -> WordSearch<'a> {
+    WordSearch {
+        dictionary: &FRENCH_DICTIONARY,
+        letters_table: &FRENCH_LETTERS_TABLE,
+        golden_word,
+        scoring,
+        selection: config.match_selection,
+        crossword: config.crossword,
+        crossing_bonus: config.crossing_bonus,
+        reading_directions: config.reading_directions,
+        reversed_word_percent: config.reversed_word_percent,
+        word_shape: config.word_shape,
+        bent_word_percent: config.bent_word_percent,
+    }
+}
+
+fn golden_word_score(scoring: &dyn ScoringRule, golden_word: &Word) -> u32 {
+    scoring
+        .score(golden_word, &[], &FRENCH_LETTERS_TABLE, golden_word)
+        .expect("unable to calculate score of golden word")
+        .total
+}
+
 fn rng_from_u32(seed: u32) -> ChaCha8Rng {
     let mut seed_bytes = [0u8; 32];
     seed_bytes[0..4].copy_from_slice(&seed.to_le_bytes());
@@ -436,6 +892,15 @@ pub struct GameSnapshot {
     /// Remaining clock time in milliseconds.
     pub clock_remaining_ms: u32,
     grid: Vec<Cell>,
+    board_mask: Vec<u8>,
+    path_preview: Option<PathPreview>,
+    /// Current combo of the player.
+    pub combo: Combo,
+    /// Shape of the cells, telling how to draw them around their centre.
+    pub topology: GridTopology,
+    shared_cells: Vec<Position>,
+    cascade: Vec<CascadeStep>,
+    claim_error: Option<ClaimError>,
     found_words: Vec<FoundWord>,
 }
 
@@ -445,20 +910,93 @@ impl GameSnapshot {
         self.grid.clone()
     }
 
+    /// Shape of the board, row by row, 1 for a cell of the board and 0 for a cell removed from
+    /// it.
+    pub fn board_mask(&self) -> Vec<u8> {
+        self.board_mask.clone()
+    }
+
+    pub fn path_preview(&self) -> Option<PathPreview> {
+        self.path_preview.clone()
+    }
+
+    /// Cells shared by crossing words during the last removal of words.
+    pub fn shared_cells(&self) -> Vec<Position> {
+        self.shared_cells.clone()
+    }
+
+    /// Steps of the last cascade of cleared words, the first step being the action itself.
+    pub fn cascade(&self) -> Vec<CascadeStep> {
+        self.cascade.clone()
+    }
+
+    /// Why the last word traced by the player could not be claimed, if it could not.
+    pub fn claim_error(&self) -> Option<ClaimError> {
+        self.claim_error
+    }
+
     pub fn found_words(&self) -> Vec<FoundWord> {
         self.found_words.clone()
     }
 }
 
+/// Words cleared at one step of a cascade.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CascadeStep {
+    /// Index of the step, 0 for the words cleared by the action itself.
+    pub step: u32,
+    /// Number of words cleared at this step.
+    pub words: u32,
+    /// Multiplier applied to the words of this step, in percent.
+    pub multiplier_percent: u32,
+}
+
+/// Preview of the move currently drawn by the player.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct PathPreview {
+    positions: Vec<Position>,
+    directions: Vec<Direction>,
+}
+
+#[wasm_bindgen]
+impl PathPreview {
+    /// Ordered positions of the path, from the moved letter to its destination.
+    pub fn positions(&self) -> Vec<Position> {
+        self.positions.clone()
+    }
+
+    /// Direction of each segment of the path, `directions[i]` goes from
+    /// `positions[i]` to `positions[i + 1]`.
+    pub fn directions(&self) -> Vec<Direction> {
+        self.directions.clone()
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Copy)]
 pub struct Cell {
-    /// Pathing status of the cell.
+    /// Real position of the cell in the grid.
     pub position: Position,
+    /// Position where the content of the cell is previewed while a move is drawn.
+    pub preview_position: Option<Position>,
+    /// Horizontal centre of the cell as drawn by the topology of the grid, in cell widths.
+    pub center_x: f32,
+    /// Vertical centre of the cell as drawn by the topology of the grid, in cell widths.
+    pub center_y: f32,
     /// Pathing status of the cell.
     pub pathing_status: CellPathingStatus,
     /// Letter of the cell.
     pub letter: u8,
+    /// Whether the cell is a wall.
+    pub wall: bool,
+    /// Premium of the cell.
+    pub premium: Option<Premium>,
+    /// Number of triplet drops the letter of the cell has sat unused for.
+    pub age: u32,
+    /// Whether the letter of the cell has turned to stone.
+    pub locked: bool,
 }
 
 /// Pathing status of a cell.
@@ -478,11 +1016,40 @@ pub fn is_empty_cell(cell: u8) -> bool {
     cell == LETTER_INDEX_NONE
 }
 
+/// Kind of the tile of a non-empty cell.
+#[wasm_bindgen]
+pub fn tile_kind(cell: u8) -> TileKind {
+    LetterIndex::from(cell).tile_kind()
+}
+
+/// Index of the letter shown by the tile of a cell, golden tiles showing their plain letter.
+#[wasm_bindgen]
+pub fn tile_letter(cell: u8) -> u8 {
+    LetterIndex::from(cell).plain().0
+}
+
 #[wasm_bindgen]
 #[derive(Clone)]
 pub struct FoundWord {
     word: String,
-    score: u16,
+    length: usize,
+    score: u32,
+    golden_word: bool,
+    positions: Vec<Position>,
+    direction: Option<Direction>,
+    letter_scores: Vec<u32>,
+    letter_multipliers: Vec<u32>,
+    length_multiplier: u32,
+    word_multiplier: u32,
+    golden_bonus: u32,
+    crossing_bonus: u32,
+    reversed_percent: u32,
+    bent_percent: u32,
+    combo_multiplier_percent: u32,
+    cascade_step: u32,
+    cascade_multiplier_percent: u32,
+    found_at_tick: u32,
+    found_at_move: u32,
 }
 
 #[wasm_bindgen]
@@ -491,7 +1058,425 @@ impl FoundWord {
         self.word.clone()
     }
 
-    pub fn score(&self) -> u16 {
+    /// Number of letters of the word.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn score(&self) -> u32 {
         self.score
     }
+
+    /// Whether the word is the golden word.
+    pub fn is_golden_word(&self) -> bool {
+        self.golden_word
+    }
+
+    /// Positions of the letters of the word in the grid, in reading order.
+    pub fn positions(&self) -> Vec<Position> {
+        self.positions.clone()
+    }
+
+    /// Reading direction of the word, unset if the word is not straight.
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    /// Score of each letter of the word, in reading order, letter premiums included.
+    pub fn letter_scores(&self) -> Vec<u32> {
+        self.letter_scores.clone()
+    }
+
+    /// Multiplier applied by a premium to each letter of the word, in reading order.
+    pub fn letter_multipliers(&self) -> Vec<u32> {
+        self.letter_multipliers.clone()
+    }
+
+    /// Multiplier applied to the letter scores because of the word length.
+    pub fn length_multiplier(&self) -> u32 {
+        self.length_multiplier
+    }
+
+    /// Multiplier applied because of the word premiums covered by the word.
+    pub fn word_multiplier(&self) -> u32 {
+        self.word_multiplier
+    }
+
+    /// Bonus given because the word is the golden word, 0 otherwise.
+    pub fn golden_bonus(&self) -> u32 {
+        self.golden_bonus
+    }
+
+    /// Bonus given for the letters shared with crossing words.
+    pub fn crossing_bonus(&self) -> u32 {
+        self.crossing_bonus
+    }
+
+    /// Percentage of the score kept because the word is read backwards, 100 otherwise.
+    pub fn reversed_percent(&self) -> u32 {
+        self.reversed_percent
+    }
+
+    /// Percentage of the score kept because the word is not straight, 100 otherwise.
+    pub fn bent_percent(&self) -> u32 {
+        self.bent_percent
+    }
+
+    /// Multiplier applied because of the combo when the word was found, in percent.
+    pub fn combo_multiplier_percent(&self) -> u32 {
+        self.combo_multiplier_percent
+    }
+
+    /// Step of the cascade during which the word was cleared, 0 if cleared by the action itself.
+    pub fn cascade_step(&self) -> u32 {
+        self.cascade_step
+    }
+
+    /// Multiplier applied because of the cascade step, in percent.
+    pub fn cascade_multiplier_percent(&self) -> u32 {
+        self.cascade_multiplier_percent
+    }
+
+    /// Tick during which the word was found.
+    pub fn found_at_tick(&self) -> u32 {
+        self.found_at_tick
+    }
+
+    /// Number of moves done by the player when the word was found.
+    pub fn found_at_move(&self) -> u32 {
+        self.found_at_move
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const CLOCK_MS: u32 = 1000;
+
+    /// Lets the clock expire until the game ends or the number of ticks is reached.
+    fn expire_clock(game: &mut Game, ticks: usize) {
+        for _ in 0..ticks {
+            if game.state == GameState::Finished {
+                return;
+            }
+
+            game.tick(CLOCK_MS, vec![], None);
+        }
+    }
+
+    #[test]
+    fn game_finishes_when_the_pool_is_exhausted() {
+        // the grid is big enough to receive the whole pool
+        let mut game = Game::new(CLOCK_MS, 16, 16, 42);
+
+        expire_clock(&mut game, 100);
+
+        assert!(game.state == GameState::Finished);
+        assert_eq!(game.end_reason(), Some(EndReason::PoolExhausted));
+        assert_eq!(game.triplets_current_index as usize, game.triplets.len());
+    }
+
+    #[test]
+    fn game_refills_the_pool_when_configured() {
+        let config = GameConfig {
+            pool_exhaustion: PoolExhaustionPolicy::Refill,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 16, 16, 42, config);
+        let initial_triplets = game.triplets.len();
+
+        expire_clock(&mut game, initial_triplets + 1);
+
+        assert!(game.state == GameState::OnGoing);
+        assert_eq!(game.end_reason(), None);
+        assert_eq!(game.triplets.len(), initial_triplets * 2);
+        assert_eq!(game.triplets_current_index as usize, initial_triplets + 1);
+    }
+
+    #[test]
+    fn game_finishes_when_the_grid_is_full() {
+        let mut game = Game::new(CLOCK_MS, 4, 4, 42);
+
+        expire_clock(&mut game, 100);
+
+        assert!(game.state == GameState::Finished);
+        assert_eq!(game.end_reason(), Some(EndReason::GridFull));
+    }
+
+    #[test]
+    fn game_finishes_when_the_time_limit_is_reached() {
+        let config = GameConfig {
+            time_limit_ms: Some(CLOCK_MS * 3),
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 16, 16, 42, config);
+
+        expire_clock(&mut game, 100);
+
+        assert_eq!(game.end_reason(), Some(EndReason::TimeLimit));
+        assert_eq!(game.play_time_ms, CLOCK_MS * 3);
+    }
+
+    #[test]
+    fn summary_is_available_once_the_game_is_finished() {
+        let mut game = Game::new(CLOCK_MS, 16, 16, 42);
+        expire_clock(&mut game, 5);
+
+        assert!(game.summary().is_none());
+
+        game.abandon();
+
+        let summary = game.summary().expect("game is finished");
+        assert_eq!(summary.reason, EndReason::Abandoned);
+        assert_eq!(summary.score, game.score);
+        assert_eq!(summary.play_time_ms, CLOCK_MS * 5);
+        assert_eq!(summary.triplets_consumed, 5);
+        assert_eq!(
+            summary
+                .words_by_length()
+                .iter()
+                .map(|w| w.words().len())
+                .sum::<usize>(),
+            game.found_words.len()
+        );
+    }
+
+    #[test]
+    fn snapshot_holds_the_shape_of_the_board() {
+        let config = GameConfig {
+            board_shape: BoardShape::Plus,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 6, 6, 42, config);
+
+        let mask = game.tick(0, vec![], None).board_mask();
+
+        assert_eq!(mask.len(), 36);
+        assert_eq!(mask.iter().filter(|&&m| m == 1).count(), 20);
+        assert_eq!((mask[0], mask[2]), (0, 1));
+    }
+
+    #[test]
+    fn shared_cells_are_those_of_the_last_action() {
+        let config = GameConfig {
+            crossword: true,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 6, 6, 42, config);
+        game.golden_word = FRENCH_LETTERS_TABLE.parse_word("NUDITE").unwrap();
+        game.grid = crate::grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', 'U']
+            [' ', ' ', ' ', ' ', ' ', 'D']
+            [' ', ' ', ' ', ' ', ' ', 'I']
+            [' ', ' ', ' ', ' ', ' ', 'T']
+            [' ', ' ', ' ', ' ', ' ', 'E']
+        )
+        .unwrap();
+
+        game.remove_found_words_in_grid(Action::Move);
+        assert_eq!(game.found_words.len(), 2);
+        assert_eq!(game.shared_cells, vec![Position::new(5, 0)]);
+
+        game.remove_found_words_in_grid(Action::Move);
+        assert!(game.shared_cells.is_empty());
+    }
+
+    #[test]
+    fn cells_outside_of_the_board_are_ignored() {
+        let config = GameConfig {
+            board_shape: BoardShape::Plus,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 6, 6, 42, config);
+        let letter = *game
+            .grid
+            .cells()
+            .iter()
+            .find(|(_, cell)| matches!(cell, grid::Cell::Letter(_)))
+            .unwrap()
+            .0;
+        let corner = Position::new(0, 0);
+
+        game.tick(0, vec![letter], Some(corner));
+        let snapshot = game.tick(0, vec![corner], Some(corner));
+
+        assert_eq!(game.path_from, Some(letter));
+        assert_eq!(snapshot.path_preview().unwrap().positions, vec![letter]);
+        assert_eq!(game.moves, 0);
+    }
+
+    #[test]
+    fn words_stay_in_the_grid_until_claimed() {
+        let config = GameConfig {
+            word_claiming: WordClaiming::Manual,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 6, 6, 42, config);
+        game.grid = crate::grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let start = Position::new(0, 0);
+        let end = Position::new(5, 0);
+
+        game.remove_found_words_in_grid(Action::Placement);
+        assert!(game.found_words.is_empty());
+
+        assert_eq!(
+            game.claim(start, Position::new(4, 1)).err(),
+            Some(ClaimError::NotAligned)
+        );
+        assert_eq!(
+            game.claim(start, Position::new(0, 5)).err(),
+            Some(ClaimError::MissingLetter)
+        );
+
+        let claimed = game.claim(start, end).unwrap();
+        assert_eq!(claimed.word(), "MAISON");
+        assert_eq!(game.score, claimed.score());
+        assert_eq!(*game.grid.cell(&start), grid::Cell::Empty);
+
+        assert_eq!(
+            game.claim(start, end).err(),
+            Some(ClaimError::MissingLetter)
+        );
+    }
+
+    #[test]
+    fn words_are_claimed_by_clicking_their_first_and_last_letters() {
+        let config = GameConfig {
+            word_claiming: WordClaiming::Manual,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 6, 6, 42, config);
+        game.grid = crate::grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let start = Position::new(0, 0);
+
+        let snapshot = game.tick(0, vec![start, Position::new(4, 0)], None);
+        assert_eq!(snapshot.claim_error(), Some(ClaimError::UnknownWord));
+        assert!(snapshot.found_words().is_empty());
+
+        let snapshot = game.tick(0, vec![start, Position::new(5, 0)], None);
+        assert_eq!(snapshot.claim_error(), None);
+        assert_eq!(
+            snapshot
+                .found_words()
+                .iter()
+                .map(FoundWord::word)
+                .collect::<Vec<_>>(),
+            vec!["MAISON"]
+        );
+        assert_eq!(game.moves, 0);
+    }
+
+    #[test]
+    fn words_cannot_be_claimed_when_removed_automatically() {
+        let mut game = Game::new(CLOCK_MS, 6, 6, 42);
+
+        assert_eq!(
+            game.claim(Position::new(0, 0), Position::new(5, 0)).err(),
+            Some(ClaimError::NotAllowed)
+        );
+    }
+
+    #[test]
+    fn fallen_letters_cascade_into_new_words() {
+        let config = GameConfig {
+            gravity: true,
+            cascade_bonus_percent: 50,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 6, 6, 42, config);
+        game.golden_word = FRENCH_LETTERS_TABLE.parse_word("NUDITE").unwrap();
+        game.grid = crate::grid!(
+            [' ', ' ', ' ', ' ', ' ', ' ']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+            [' ', ' ', ' ', ' ', ' ', 'N']
+            ['Z', 'E', 'B', 'R', 'E', 'S']
+            ['M', 'A', 'I', 'S', 'O', ' ']
+        )
+        .unwrap();
+
+        game.remove_found_words_in_grid(Action::Move);
+
+        let words: Vec<(String, u32, u32)> = game
+            .found_words
+            .iter()
+            .map(|w| (w.word(), w.cascade_step(), w.score()))
+            .collect();
+        assert_eq!(
+            words,
+            vec![
+                ("ZEBRES".to_string(), 0, 44),
+                ("MAISON".to_string(), 1, 34 * 3 / 2)
+            ]
+        );
+        assert_eq!(game.score, 44 + 34 * 3 / 2);
+        assert_eq!(
+            game.generate_game_snapshot().cascade(),
+            vec![
+                CascadeStep {
+                    step: 0,
+                    words: 1,
+                    multiplier_percent: 100
+                },
+                CascadeStep {
+                    step: 1,
+                    words: 1,
+                    multiplier_percent: 150
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn words_completed_by_one_triplet_form_a_combo() {
+        let config = GameConfig {
+            gravity: true,
+            combo_bonus_percent: 50,
+            letter_placement: crate::config::LetterPlacement::ColumnDrop,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(CLOCK_MS, 6, 6, 42, config);
+        game.golden_word = FRENCH_LETTERS_TABLE.parse_word("NUDITE").unwrap();
+        game.grid = crate::grid!(
+            [' ', ' ', ' ', ' ', ' ', ' ']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+            ['M', 'A', 'I', 'S', 'O', ' ']
+            ['Z', 'E', 'B', 'R', 'E', ' ']
+        )
+        .unwrap();
+        game.set_drop_column(Some(5));
+        let [s, n, e] =
+            ["S", "N", "E"].map(|l| FRENCH_LETTERS_TABLE.try_get_letter_index(l).unwrap().into());
+
+        // S completes ZEBRES, then N completes MAISON once it has fallen
+        game.try_place_triplet((s, n, e));
+
+        let words: Vec<(String, u32)> = game
+            .found_words
+            .iter()
+            .map(|w| (w.word(), w.score()))
+            .collect();
+        assert_eq!(
+            words,
+            vec![
+                ("ZEBRES".to_string(), 44 * 3 / 2),
+                ("MAISON".to_string(), 34 * 3 / 2)
+            ]
+        );
+        assert_eq!(game.combo.words, 2);
+        assert_eq!(game.combo.multiplier_percent, 150);
+    }
 }
@@ -6,7 +6,16 @@ use std::{
 };
 use wasm_bindgen::prelude::*;
 
-use crate::lexicon::{Dictionary, FRENCH_LETTERS_TABLE, LetterIndex, LettersTable, Word};
+use crate::{
+    config::{
+        BoardShape, MatchSelection, MovementDirections, PremiumLifetime, ReadingDirections,
+        WordShape,
+    },
+    lexicon::{Dictionary, LetterIndex, LettersTable, TileKind, Word},
+    scoring::{LetterBonus, Premium, ScoreBreakdown, ScoringRule},
+    selection::{select_greedy, select_optimal},
+    topology::{SquareTopology, Topology},
+};
 use priority_queue::PriorityQueue;
 
 #[derive(Debug, thiserror::Error, PartialEq)]
@@ -26,6 +35,22 @@ pub(crate) enum GridError {
     MissingAMandatoryEmptyCell {},
 }
 
+/// Character marking a wall in a grid layout.
+pub const LAYOUT_WALL: char = '#';
+/// Character marking a cell removed from the board in a grid layout.
+pub const LAYOUT_HOLE: char = 'x';
+
+/// Premium marked by a character of a grid layout.
+fn layout_premium(c: char) -> Option<Premium> {
+    match c {
+        'd' => Some(Premium::DoubleLetter),
+        't' => Some(Premium::TripleLetter),
+        'D' => Some(Premium::DoubleWord),
+        'T' => Some(Premium::TripleWord),
+        _ => None,
+    }
+}
+
 /// Index of a row or column in the grid.
 pub type GridIndex = u8;
 
@@ -34,7 +59,7 @@ pub type GridSize = u8;
 
 /// A position in the grid.
 #[wasm_bindgen]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Position {
     pub x: GridIndex,
     pub y: GridIndex,
@@ -56,31 +81,35 @@ pub(crate) struct MaybePosition {
 
 impl MaybePosition {
     pub(crate) fn new(from: &Position, dir: &Direction) -> Self {
-        match dir {
-            Direction::N => MaybePosition {
-                x: from.x as i8,
-                y: from.y as i8 - 1,
-            },
-            Direction::E => MaybePosition {
-                x: from.x as i8 + 1,
-                y: from.y as i8,
-            },
-            Direction::S => MaybePosition {
-                x: from.x as i8,
-                y: from.y as i8 + 1,
-            },
-            Direction::O => MaybePosition {
-                x: from.x as i8 - 1,
-                y: from.y as i8,
-            },
+        let (dx, dy) = dir.offset();
+        MaybePosition {
+            x: from.x as i8 + dx,
+            y: from.y as i8 + dy,
         }
     }
+
+    fn is(&self, pos: &Position) -> bool {
+        self.x == pos.x as i8 && self.y == pos.y as i8
+    }
 }
 
+#[derive(Clone)]
 pub(crate) struct Grid {
     width: GridSize,
     height: GridSize,
     cells: HashMap<Position, Cell>,
+    /// Premiums of the cells, stored apart as they stay when letters move.
+    premiums: HashMap<Position, Premium>,
+    premium_lifetime: PremiumLifetime,
+    topology: &'static dyn Topology,
+    movement_directions: MovementDirections,
+    /// Whether leaving the grid by one edge continues on the opposite edge.
+    wrap_around: bool,
+    /// Number of triplet drops each letter has sat unused for.
+    ages: HashMap<Position, u32>,
+    /// Age at which a letter turns to stone, letters never do if unset.
+    lock_age: Option<u32>,
+    locked_letters_form_words: bool,
 }
 
 impl Grid {
@@ -97,10 +126,140 @@ impl Grid {
             width,
             height,
             cells,
+            premiums: HashMap::new(),
+            premium_lifetime: PremiumLifetime::Consumed,
+            topology: &SquareTopology,
+            movement_directions: MovementDirections::Orthogonal,
+            wrap_around: false,
+            ages: HashMap::new(),
+            lock_age: None,
+            locked_letters_form_words: true,
+        }
+    }
+
+    pub(crate) fn width(&self) -> GridSize {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> GridSize {
+        self.height
+    }
+
+    /// Removes the cells outside of the given board shape.
+    pub(crate) fn apply_shape(&mut self, shape: BoardShape) {
+        let (width, height) = (self.width, self.height);
+        self.cells
+            .retain(|pos, _| shape.contains(pos.x, pos.y, width, height));
+    }
+
+    /// Applies a layout to the grid, one line per row, where `#` marks a wall, `x` marks a cell
+    /// removed from the board, `d` and `t` mark double and triple letter premiums, `D` and `T`
+    /// mark double and triple word premiums.
+    ///
+    /// Other characters, and cells outside of the layout, are left untouched.
+    pub(crate) fn apply_layout(&mut self, layout: &str) {
+        for (y, row) in layout.lines().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                if x >= self.width as usize || y >= self.height as usize {
+                    continue;
+                }
+
+                let pos = Position::new(x as GridIndex, y as GridIndex);
+                match c {
+                    LAYOUT_WALL => {
+                        if let Some(cell) = self.cells.get_mut(&pos) {
+                            *cell = Cell::Wall;
+                        }
+                    }
+                    LAYOUT_HOLE => {
+                        self.cells.remove(&pos);
+                    }
+                    _ => {
+                        if let Some(premium) = layout_premium(c)
+                            && self.cells.contains_key(&pos)
+                        {
+                            self.premiums.insert(pos, premium);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sets whether the premiums are used up by the first word covering them.
+    pub(crate) fn set_premium_lifetime(&mut self, premium_lifetime: PremiumLifetime) {
+        self.premium_lifetime = premium_lifetime;
+    }
+
+    /// Premium of a cell, if any.
+    pub(crate) fn premium(&self, pos: &Position) -> Option<Premium> {
+        self.premiums.get(pos).copied()
+    }
+
+    /// Sets the shape of the cells and of their neighbourhood.
+    pub(crate) fn set_topology(&mut self, topology: &'static dyn Topology) {
+        self.topology = topology;
+    }
+
+    /// Makes the grid a torus, where leaving by one edge continues on the opposite edge.
+    pub(crate) fn set_wrap_around(&mut self, wrap_around: bool) {
+        self.wrap_around = wrap_around;
+    }
+
+    /// Sets the age at which letters turn to stone, and whether they still form words then.
+    pub(crate) fn set_lock_age(&mut self, lock_age: Option<u32>, locked_letters_form_words: bool) {
+        self.lock_age = lock_age;
+        self.locked_letters_form_words = locked_letters_form_words;
+    }
+
+    /// Number of triplet drops the letter of a cell has sat unused for.
+    pub(crate) fn age(&self, pos: &Position) -> u32 {
+        self.ages.get(pos).copied().unwrap_or_default()
+    }
+
+    /// Whether the letter of a cell has turned to stone.
+    pub(crate) fn is_locked(&self, pos: &Position) -> bool {
+        matches!(self.cells.get(pos), Some(Cell::Letter(_)))
+            && self
+                .lock_age
+                .is_some_and(|lock_age| self.age(pos) >= lock_age)
+    }
+
+    /// Ages every letter of the grid by one triplet drop.
+    pub(crate) fn age_letters(&mut self) {
+        for age in self.ages.values_mut() {
+            *age += 1;
+        }
+    }
+
+    /// Makes the letter of a cell as new, freeing it if it has turned to stone.
+    fn reset_age(&mut self, pos: &Position) {
+        match self.cells.get(pos) {
+            Some(Cell::Letter(_)) => self.ages.insert(*pos, 0),
+            _ => self.ages.remove(pos),
+        };
+    }
+
+    /// Frees the letters next to cleared cells.
+    fn free_neighbours(&mut self, cleared: &[Position]) {
+        let neighbours: Vec<Position> = cleared
+            .iter()
+            .flat_map(|pos| self.neighbours(pos))
+            .filter(|pos| matches!(self.cell(pos), Cell::Letter(_)))
+            .collect();
+
+        for pos in neighbours {
+            self.reset_age(&pos);
         }
     }
 
+    /// Sets the directions in which letters can move from one cell to another.
+    pub(crate) fn set_movement_directions(&mut self, movement_directions: MovementDirections) {
+        self.movement_directions = movement_directions;
+    }
+
     /// Creates a new grid from the given vector of positions and letters.
+    #[cfg(test)]
     pub(crate) fn from_vec(
         width: usize,
         height: usize,
@@ -112,9 +271,11 @@ impl Grid {
         let empty = Grid::empty(w, h);
 
         let filled = vec.into_iter().try_fold(empty, |mut grid, (pos, c)| {
-            let index = FRENCH_LETTERS_TABLE.try_get_letter_index(c).map_err(|_e| {
-                GridError::InvalidGridInitializationDueToUnknownLetter { letter: c }
-            })?;
+            let index = crate::lexicon::FRENCH_LETTERS_TABLE
+                .try_get_letter_index(&c.to_string())
+                .map_err(
+                    |_e| GridError::InvalidGridInitializationDueToUnknownLetter { letter: c },
+                )?;
 
             grid.update_cell(pos, Cell::Letter(index));
             Ok::<Grid, _>(grid)
@@ -125,6 +286,7 @@ impl Grid {
 
     pub(crate) fn update_cell(&mut self, pos: Position, value: Cell) {
         self.cells.insert(pos, value);
+        self.reset_age(&pos);
     }
 
     pub(crate) fn cell(&self, pos: &Position) -> &Cell {
@@ -135,31 +297,67 @@ impl Grid {
 
     /// Check if a position is in the grid.
     pub(crate) fn is_in_grid(&self, pos: MaybePosition) -> Option<Position> {
+        let pos = if self.wrap_around {
+            MaybePosition {
+                x: (pos.x as i16).rem_euclid(self.width as i16) as i8,
+                y: (pos.y as i16).rem_euclid(self.height as i16) as i8,
+            }
+        } else {
+            pos
+        };
+
         if pos.x < 0 || pos.y < 0 || pos.x >= self.width as i8 || pos.y >= self.height as i8 {
             return None;
         }
 
-        Some(Position::new(pos.x as u8, pos.y as u8))
+        // the cell may be outside of the board shape
+        let pos = Position::new(pos.x as u8, pos.y as u8);
+        self.cells.contains_key(&pos).then_some(pos)
+    }
+
+    /// Cells sharing a side with the given cell.
+    pub(crate) fn neighbours(&self, pos: &Position) -> Vec<Position> {
+        self.topology
+            .neighbour_directions()
+            .iter()
+            .filter_map(|dir| self.is_in_grid(MaybePosition::new(pos, dir)))
+            .collect()
+    }
+
+    /// Whether a side of the cell has no neighbour, being on the border of the grid or next to a
+    /// cell removed from the board.
+    pub(crate) fn is_on_edge(&self, pos: &Position) -> bool {
+        self.neighbours(pos).len() < self.topology.neighbour_directions().len()
     }
 
     pub(crate) fn cells(&self) -> &HashMap<Position, Cell> {
         &self.cells
     }
 
-    fn empty_cells(&self) -> impl Iterator<Item = &Position> {
+    pub(crate) fn empty_cells(&self) -> impl Iterator<Item = &Position> {
         self.cells.iter().filter_map(|(pos, cell)| match cell {
-            Cell::Letter(_) => None,
             Cell::Empty => Some(pos),
+            Cell::Letter(_) | Cell::Wall => None,
         })
     }
 
     fn letters(&self) -> impl Iterator<Item = (&Position, &LetterIndex)> {
         self.cells.iter().filter_map(|(pos, cell)| match cell {
             Cell::Letter(c) => Some((pos, c)),
-            Cell::Empty => None,
+            Cell::Empty | Cell::Wall => None,
         })
     }
 
+    /// Letter of a cell as read in words, bombs are not part of any word, nor are letters
+    /// turned to stone unless configured.
+    fn readable_letter(&self, pos: &Position) -> Option<LetterIndex> {
+        match self.cell(pos) {
+            _ if !self.locked_letters_form_words && self.is_locked(pos) => None,
+            Cell::Letter(c) if c.tile_kind() != TileKind::Bomb => Some(c.plain()),
+            _ => None,
+        }
+    }
+
     pub(crate) fn random_empty_cell_position<R>(&self, rng: &mut R) -> Option<Position>
     where
         R: Rng + ?Sized,
@@ -189,54 +387,64 @@ impl Grid {
 
 /// Find and extract words from the grid.
 impl Grid {
-    pub fn get_words(
-        &self,
-        dictionary: &Dictionary,
-        letter_table: &LettersTable,
-        golden_word: &Word,
-    ) -> Vec<Match> {
-        let mut all_matches = self.find_words(dictionary, letter_table, golden_word);
-
-        all_matches.sort_by(
-            |m1, m2| m2.score.cmp(&m1.score), // bigger score first
-        );
+    pub fn get_words(&self, search: &WordSearch) -> Vec<Match> {
+        let mut all_matches = self.find_words(search);
+
+        // bigger score first, then straighter words, positions keep the order independent of
+        // the cells storage
+        all_matches.sort_by(|m1, m2| {
+            m2.score()
+                .cmp(&m1.score())
+                .then_with(|| {
+                    self.number_of_angles(&m1.positions)
+                        .cmp(&self.number_of_angles(&m2.positions))
+                })
+                .then_with(|| m1.positions.cmp(&m2.positions))
+        });
 
-        // filter matches that use letters from other matches with higher scores
+        // around a loop of the torus, the same letters can be read from several roots
+        let all_matches = all_matches.into_iter().unique_by(|m| {
+            let cells: Vec<Position> = m.positions.iter().copied().sorted().collect();
+            (m.word.clone(), cells)
+        });
 
-        let mut matches = Vec::new();
-        let mut already_used_letters = HashSet::<Position>::new();
+        // the golden word is never masked by other words
+        let (golden_matches, other_matches): (Vec<Match>, Vec<Match>) = all_matches
+            .into_iter()
+            .partition(|m| m.word == *search.golden_word);
+        let mut matches = select_greedy(golden_matches, search.crossword);
+        let other_matches = other_matches
+            .into_iter()
+            .filter(|m| {
+                matches
+                    .iter()
+                    .all(|golden| m.is_compatible_with(golden, search.crossword))
+            })
+            .collect();
 
-        for m in all_matches {
-            if m.positions
-                .iter()
-                .any(|pos| already_used_letters.contains(pos))
-            {
-                // letters can only be used by one word
-                continue;
-            }
+        // letters can only be used by one word, except at crossings in crossword mode
+        matches.extend(match search.selection {
+            MatchSelection::Greedy => select_greedy(other_matches, search.crossword),
+            MatchSelection::Optimal => select_optimal(other_matches, search.crossword),
+        });
 
-            already_used_letters.extend(m.positions.iter());
-            matches.push(m);
+        // the crossings are only known once the words are selected, so the bonus does not weigh
+        // in the selection
+        if search.crossword {
+            add_crossing_bonus(&mut matches, search.crossing_bonus);
         }
 
         matches
     }
 
-    fn find_words(
-        &self,
-        dictionary: &Dictionary,
-        letter_table: &LettersTable,
-        golden_word: &Word,
-    ) -> Vec<Match> {
+    fn find_words(&self, search: &WordSearch) -> Vec<Match> {
         self.letters()
+            .filter_map(|(pos, _)| Some((*pos, self.readable_letter(pos)?)))
             .fold(Vec::new(), |mut matches, (root_pos, root_letter)| {
-                let matches_from_pos = self.find_words_from(
-                    *root_pos,
-                    *root_letter,
-                    dictionary,
-                    letter_table,
-                    golden_word,
-                );
+                let matches_from_pos = match search.word_shape {
+                    WordShape::Straight => self.find_words_from(root_pos, root_letter, search),
+                    WordShape::Bent => self.find_bent_words_from(root_pos, root_letter, search),
+                };
 
                 matches.extend(matches_from_pos);
                 matches
@@ -247,21 +455,14 @@ impl Grid {
         &self,
         pos: Position,
         letter: LetterIndex,
-        dictionary: &Dictionary,
-        letter_table: &LettersTable,
-        golden_word: &Word,
+        search: &WordSearch,
     ) -> Vec<Match> {
-        Direction::all()
+        self.topology
+            .reading_directions(search.reading_directions)
             .into_iter()
             .fold(Vec::new(), |mut matches, dir| {
-                let matches_in_direction = self.find_words_in_one_direction_from(
-                    pos,
-                    letter,
-                    dir,
-                    dictionary,
-                    letter_table,
-                    golden_word,
-                );
+                let matches_in_direction =
+                    self.find_words_in_one_direction_from(pos, letter, dir, search);
 
                 matches.extend(matches_in_direction.into_iter());
                 matches
@@ -273,9 +474,7 @@ impl Grid {
         pos: Position,
         letter: LetterIndex,
         dir: Direction,
-        dictionary: &Dictionary,
-        letter_table: &LettersTable,
-        golden_word: &Word,
+        search: &WordSearch,
     ) -> Vec<Match> {
         let mut matches = vec![];
 
@@ -287,11 +486,13 @@ impl Grid {
         loop {
             match self.is_in_grid(to_check) {
                 None => break,
-                Some(pos) => match self.cell(&pos) {
-                    Cell::Empty => break,
-                    Cell::Letter(c) => {
+                // back to the root around the torus
+                Some(pos) if positions.contains(&pos) => break,
+                Some(pos) => match self.readable_letter(&pos) {
+                    None => break,
+                    Some(c) => {
                         positions.push(pos);
-                        letters.push(*c);
+                        letters.push(c);
 
                         // next position in the given direction
                         to_check = MaybePosition::new(&pos, &dir);
@@ -304,63 +505,304 @@ impl Grid {
             }
         }
 
-        loop {
-            // Not enough letters to form a word in that direction
-            if positions.len() < Word::MIN_LENGTH {
-                return matches;
+        // Every word starting at the root is kept, a shorter one can score more than a longer
+        // one, the selection picks the best ones among them
+        while positions.len() >= Word::MIN_LENGTH {
+            if let Some(word) = search.dictionary.find(&letters, search.golden_word) {
+                let bonuses = self.bonuses_along(&positions);
+                matches.push(Match::new(
+                    word,
+                    positions.clone(),
+                    Some(dir),
+                    &bonuses,
+                    self.topology,
+                    search,
+                ));
             }
 
-            let word = Word::new(letters.clone())
-                .expect("word lenght should be okay as it is checked before");
+            positions.pop();
+            letters.pop();
+        }
 
-            if dictionary.contains(&word) {
-                let score = word
-                    .score(letter_table, golden_word)
-                    .expect("unable to generate score of a word in the grid");
+        matches
+    }
 
-                matches.push(Match {
-                    word,
-                    positions,
-                    score,
-                });
+    /// Finds the words made of a chain of orthogonally adjacent letters starting at the given
+    /// position, each letter being used once at most.
+    fn find_bent_words_from(
+        &self,
+        pos: Position,
+        letter: LetterIndex,
+        search: &WordSearch,
+    ) -> Vec<Match> {
+        let mut matches = vec![];
+        self.extend_bent_words(&mut vec![pos], &mut vec![letter], search, &mut matches);
+        matches
+    }
 
-                // We only care about the longuest word because it will be the biggest score
-                return matches;
+    fn extend_bent_words(
+        &self,
+        positions: &mut Vec<Position>,
+        letters: &mut Vec<LetterIndex>,
+        search: &WordSearch,
+        matches: &mut Vec<Match>,
+    ) {
+        // no word can be made by adding letters to this chain
+        if !search.dictionary.has_prefix(letters) {
+            return;
+        }
+
+        if letters.len() >= Word::MIN_LENGTH
+            && let Some(word) = search.dictionary.find(letters, search.golden_word)
+        {
+            let direction = self.straight_direction(positions);
+            let bonuses = self.bonuses_along(positions);
+            matches.push(Match::new(
+                word,
+                positions.clone(),
+                direction,
+                &bonuses,
+                self.topology,
+                search,
+            ));
+        }
+
+        if letters.len() == Word::MAX_LENGTH {
+            return;
+        }
+
+        let head = *positions
+            .last()
+            .expect("a chain has at least one position, the starting one");
+
+        for next in self.neighbours(&head) {
+            if positions.contains(&next) {
+                continue;
             }
 
-            positions.pop();
-            letters.pop();
+            if let Some(c) = self.readable_letter(&next) {
+                positions.push(next);
+                letters.push(c);
+
+                self.extend_bent_words(positions, letters, search, matches);
+
+                positions.pop();
+                letters.pop();
+            }
         }
     }
 
-    pub fn retrieve_words(
-        &mut self,
-        dictionary: &Dictionary,
-        letter_table: &LettersTable,
-        golden_word: &Word,
-    ) -> Vec<Match> {
-        let matches = self.get_words(dictionary, letter_table, golden_word);
+    /// Whether a word of the dictionary goes through the given position.
+    pub(crate) fn has_word_at(&self, pos: &Position, search: &WordSearch) -> bool {
+        self.find_words(search)
+            .iter()
+            .any(|m| m.positions.contains(pos))
+    }
+
+    /// Empties the cell of a retrieved letter, its premium is used up unless permanent.
+    fn clear_cell(&mut self, pos: &Position) {
+        self.cells.insert(*pos, Cell::Empty);
+        self.ages.remove(pos);
+        if self.premium_lifetime == PremiumLifetime::Consumed {
+            self.premiums.remove(pos);
+        }
+    }
+
+    /// Bonuses of the letters of a word, in reading order.
+    fn bonuses_along(&self, positions: &[Position]) -> Vec<LetterBonus> {
+        positions
+            .iter()
+            .map(|pos| {
+                let kind = match self.cell(pos) {
+                    Cell::Letter(c) => c.tile_kind(),
+                    Cell::Empty | Cell::Wall => TileKind::Letter,
+                };
+
+                LetterBonus {
+                    premium: self.premium(pos),
+                    wildcard: kind == TileKind::Wildcard,
+                    golden: kind == TileKind::Golden,
+                }
+            })
+            .collect()
+    }
+
+    /// Clears the letters around an exploding bomb, including the bomb itself. Walls are left
+    /// untouched.
+    pub(crate) fn explode(&mut self, center: Position) -> Vec<Position> {
+        let mut cleared: Vec<Position> = self
+            .topology
+            .movement_directions(MovementDirections::All)
+            .iter()
+            .filter_map(|dir| self.is_in_grid(MaybePosition::new(&center, dir)))
+            .chain(std::iter::once(center))
+            .filter(|pos| matches!(self.cell(pos), Cell::Letter(_)))
+            .collect();
+        cleared.sort();
+        cleared.dedup();
+
+        for pos in cleared.iter() {
+            self.cells.insert(*pos, Cell::Empty);
+            self.ages.remove(pos);
+        }
+
+        cleared
+    }
+
+    pub fn retrieve_words(&mut self, search: &WordSearch) -> Vec<Match> {
+        let matches = self.get_words(search);
         for m in matches.iter() {
             for pos in m.positions.iter() {
-                self.cells.insert(*pos, Cell::Empty);
+                self.clear_cell(pos);
             }
         }
+        for m in matches.iter() {
+            self.free_neighbours(&m.positions);
+        }
 
         matches
     }
+
+    /// Removes the straight word traced by the player from `start` to `end`.
+    pub(crate) fn claim_word(
+        &mut self,
+        start: Position,
+        end: Position,
+        search: &WordSearch,
+    ) -> Result<Match, ClaimError> {
+        let dx = end.x as i16 - start.x as i16;
+        let dy = end.y as i16 - start.y as i16;
+        if dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+            return Err(ClaimError::NotAligned);
+        }
+
+        let length = dx.abs().max(dy.abs()) as usize + 1;
+        if !(Word::MIN_LENGTH..=Word::MAX_LENGTH).contains(&length) {
+            return Err(ClaimError::InvalidLength);
+        }
+
+        let step = (dx.signum() as i8, dy.signum() as i8);
+        let dir = Direction::all()
+            .into_iter()
+            .find(|dir| dir.offset() == step)
+            .expect("the start and end of a word are aligned and distinct");
+        if !self
+            .topology
+            .reading_directions(search.reading_directions)
+            .contains(&dir)
+        {
+            return Err(ClaimError::ForbiddenDirection);
+        }
+
+        if !self.cells.contains_key(&start) {
+            return Err(ClaimError::MissingLetter);
+        }
+
+        let mut positions = vec![start];
+        for _ in 1..length {
+            let last = positions[positions.len() - 1];
+            let next = self
+                .is_in_grid(MaybePosition::new(&last, &dir))
+                .ok_or(ClaimError::MissingLetter)?;
+            positions.push(next);
+        }
+
+        let letters = positions
+            .iter()
+            .map(|pos| self.readable_letter(pos).ok_or(ClaimError::MissingLetter))
+            .collect::<Result<Vec<LetterIndex>, ClaimError>>()?;
+
+        let word = search
+            .dictionary
+            .find(&letters, search.golden_word)
+            .ok_or(ClaimError::UnknownWord)?;
+
+        let bonuses = self.bonuses_along(&positions);
+        for pos in positions.iter() {
+            self.clear_cell(pos);
+        }
+        self.free_neighbours(&positions);
+
+        Ok(Match::new(
+            word,
+            positions,
+            Some(dir),
+            &bonuses,
+            self.topology,
+            search,
+        ))
+    }
+}
+
+/// Reason why a word traced by the player cannot be claimed.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ClaimError {
+    /// Words are not claimed by the player with the current rules, or the game is finished.
+    NotAllowed,
+    /// The start and end cells are not on the same row, column or diagonal.
+    NotAligned,
+    /// The word is too short or too long.
+    InvalidLength,
+    /// Words cannot be read in this direction with the current rules.
+    ForbiddenDirection,
+    /// A cell between the start and the end does not hold a letter.
+    MissingLetter,
+    /// The letters do not form a word of the dictionary.
+    UnknownWord,
 }
 
 /// Pathing and movements in the grid.
 impl Grid {
+    /// Lets the letters of the columns of the given cells fall down into the empty cells below
+    /// them.
+    ///
+    /// Returns whether a letter fell.
+    pub(crate) fn apply_gravity(&mut self, cleared: &[Position]) -> bool {
+        let mut fell = false;
+
+        for x in cleared.iter().map(|pos| pos.x).unique() {
+            // next row to fill, from the bottom of the column
+            let mut bottom = self.height;
+
+            for y in (0..self.height).rev() {
+                let pos = Position::new(x, y);
+                match self.cells.get(&pos).copied() {
+                    Some(Cell::Letter(c)) => {
+                        bottom -= 1;
+                        if bottom != y {
+                            let to = Position::new(x, bottom);
+                            self.cells.insert(to, Cell::Letter(c));
+                            self.cells.insert(pos, Cell::Empty);
+                            if let Some(age) = self.ages.remove(&pos) {
+                                self.ages.insert(to, age);
+                            }
+                            fell = true;
+                        }
+                    }
+                    // letters above a wall, or a hole in the board, fall onto it
+                    Some(Cell::Wall) | None => bottom = y,
+                    Some(Cell::Empty) => {}
+                }
+            }
+        }
+
+        fell
+    }
+
     pub fn allowed_moving_positions(&self, from: Position) -> HashSet<Position> {
         let mut allowed_positions = HashSet::<Position>::new();
         allowed_positions.insert(from);
 
         fn check_around(pos: Position, grid: &Grid, registry: &mut HashSet<Position>) {
-            for direction in Direction::all() {
+            for direction in grid.topology.movement_directions(grid.movement_directions) {
                 let to_check = MaybePosition::new(&pos, &direction);
                 if let Some(to_check) = grid.is_in_grid(to_check) {
-                    if registry.contains(&to_check) {
+                    if registry.contains(&to_check)
+                        || grid.cell(&to_check) == &Cell::Wall
+                        || grid.is_locked(&to_check)
+                    {
                         continue;
                     }
 
@@ -415,7 +857,7 @@ impl Grid {
                 continue;
             }
 
-            for direction in Direction::all() {
+            for direction in self.topology.movement_directions(self.movement_directions) {
                 let to_check = MaybePosition::new(head, &direction);
                 match self.is_in_grid(to_check) {
                     None => continue,
@@ -448,7 +890,7 @@ impl Grid {
         shortest_paths
             .into_iter()
             .map(|path| {
-                let number_of_angles = number_of_angles(&path);
+                let number_of_angles = self.number_of_angles(&path);
                 (path, number_of_angles)
             })
             .min_by(|(_, n1), (_, n2)| n1.cmp(n2))
@@ -456,6 +898,15 @@ impl Grid {
     }
 
     fn path_exists(&self, from: &Position, to: &Position) -> bool {
+        // either end may be outside of the board shape
+        if !self.cells.contains_key(from) || !self.cells.contains_key(to) {
+            return false;
+        }
+
+        if self.cell(from) == &Cell::Wall || self.cell(to) == &Cell::Wall {
+            return false;
+        }
+
         if *from == *to {
             return true;
         }
@@ -468,7 +919,7 @@ impl Grid {
         let mut need_to_check_around: Vec<Position> = vec![*from];
 
         while let Some(check_around) = need_to_check_around.pop() {
-            for direction in Direction::all() {
+            for direction in self.topology.movement_directions(self.movement_directions) {
                 let to_check = MaybePosition::new(&check_around, &direction);
                 let to_check = match self.is_in_grid(to_check) {
                     None => continue,
@@ -486,7 +937,7 @@ impl Grid {
                 checked.insert(to_check);
 
                 match self.cell(&to_check) {
-                    Cell::Letter(_) => continue,
+                    Cell::Letter(_) | Cell::Wall => continue,
                     Cell::Empty => need_to_check_around.push(to_check),
                 }
             }
@@ -496,6 +947,10 @@ impl Grid {
     }
 
     pub fn move_cell(&mut self, from: Position, to: Position) -> MoveResult {
+        if self.is_locked(&from) || self.is_locked(&to) {
+            return MoveResult::Locked;
+        }
+
         if !self.path_exists(&from, &to) {
             return MoveResult::NoPath;
         }
@@ -505,69 +960,247 @@ impl Grid {
 
         self.cells.insert(to, from_cell);
         self.cells.insert(from, to_cell);
+        self.reset_age(&from);
+        self.reset_age(&to);
 
         MoveResult::Moved
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-enum Orientation {
+pub(crate) enum Orientation {
     Horizontal,
     Vertical,
+    /// From the top left to the bottom right.
+    DiagonalDown,
+    /// From the bottom left to the top right.
+    DiagonalUp,
 }
 
-fn number_of_angles(path: &Vec<Position>) -> usize {
-    path.iter()
-        .tuple_windows()
-        .map(|(p1, p2)| {
-            if p1.x == p2.x {
-                Orientation::Horizontal
-            } else if p1.y == p2.y {
-                Orientation::Vertical
-            } else {
-                panic!("Invalid path")
-            }
-        })
-        .tuple_windows()
-        .fold(
-            0,
-            |count, (o1, o2)| {
-                if o1 != o2 { count + 1 } else { count }
-            },
-        )
+/// Directions along paths in the grid.
+impl Grid {
+    /// Direction leading from a cell to a neighbour cell, across the edges on a torus.
+    pub(crate) fn direction_between(&self, from: &Position, to: &Position) -> Option<Direction> {
+        if !self.wrap_around {
+            return Direction::between(from, to);
+        }
+
+        Direction::all()
+            .into_iter()
+            .find(|dir| self.is_in_grid(MaybePosition::new(from, dir)) == Some(*to))
+    }
+
+    /// Direction of the path if all its positions are aligned.
+    fn straight_direction(&self, path: &[Position]) -> Option<Direction> {
+        path.iter()
+            .tuple_windows()
+            .map(|(p1, p2)| self.direction_between(p1, p2))
+            .all_equal_value()
+            .ok()
+            .flatten()
+    }
+
+    fn number_of_angles(&self, path: &[Position]) -> usize {
+        path.iter()
+            .tuple_windows()
+            .map(|(p1, p2)| self.direction_between(p1, p2).expect("Invalid path"))
+            .tuple_windows()
+            .fold(
+                0,
+                |count, (o1, o2)| {
+                    if o1 != o2 { count + 1 } else { count }
+                },
+            )
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum MoveResult {
     Moved,
     NoPath,
+    /// One of the letters has turned to stone.
+    Locked,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub(crate) enum Direction {
+/// A direction in the grid.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Direction {
     N,
     E,
     S,
     O,
+    NE,
+    SE,
+    SO,
+    NO,
 }
 
 impl Direction {
-    fn all() -> Vec<Direction> {
+    /// Directions along rows and columns.
+    pub(crate) fn orthogonal() -> Vec<Direction> {
         vec![Direction::N, Direction::E, Direction::S, Direction::O]
     }
+
+    /// Directions along diagonals.
+    pub(crate) fn diagonal() -> Vec<Direction> {
+        vec![Direction::NE, Direction::SE, Direction::SO, Direction::NO]
+    }
+
+    pub(crate) fn all() -> Vec<Direction> {
+        let mut all = Direction::orthogonal();
+        all.extend(Direction::diagonal());
+        all
+    }
+
+    /// Offset of the columns and rows when moving one cell in this direction.
+    pub(crate) fn offset(&self) -> (i8, i8) {
+        match self {
+            Direction::N => (0, -1),
+            Direction::E => (1, 0),
+            Direction::S => (0, 1),
+            Direction::O => (-1, 0),
+            Direction::NE => (1, -1),
+            Direction::SE => (1, 1),
+            Direction::SO => (-1, 1),
+            Direction::NO => (-1, -1),
+        }
+    }
+
+    pub(crate) fn orientation(&self) -> Orientation {
+        match self {
+            Direction::N | Direction::S => Orientation::Vertical,
+            Direction::E | Direction::O => Orientation::Horizontal,
+            Direction::SE | Direction::NO => Orientation::DiagonalDown,
+            Direction::NE | Direction::SO => Orientation::DiagonalUp,
+        }
+    }
+
+    /// Direction to follow to go from one position to an adjacent one.
+    pub(crate) fn between(from: &Position, to: &Position) -> Option<Direction> {
+        Direction::all()
+            .into_iter()
+            .find(|dir| MaybePosition::new(from, dir).is(to))
+    }
+}
+
+/// Everything needed to find and score words in the grid.
+pub struct WordSearch<'a> {
+    pub dictionary: &'a Dictionary,
+    pub letters_table: &'a LettersTable,
+    pub golden_word: &'a Word,
+    pub scoring: &'a dyn ScoringRule,
+    pub selection: MatchSelection,
+    /// Whether horizontal and vertical words can share the letter where they cross.
+    pub crossword: bool,
+    /// Bonus earned by a word for each letter shared with a crossing word, added once the words
+    /// are selected.
+    pub crossing_bonus: u32,
+    /// Directions in which words can be read.
+    pub reading_directions: ReadingDirections,
+    /// Percentage of the score kept by words read backwards.
+    pub reversed_word_percent: u32,
+    /// Shape of the words, the reading directions only apply to straight words.
+    pub word_shape: WordShape,
+    /// Percentage of the score kept by words that are not straight.
+    pub bent_word_percent: u32,
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Match {
     pub word: Word,
-    pub score: u16,
+    pub breakdown: ScoreBreakdown,
     pub positions: Vec<Position>,
+    /// Reading direction of the word, from its first letter, unset if the word is not straight.
+    pub direction: Option<Direction>,
+}
+
+impl Match {
+    fn new(
+        word: Word,
+        positions: Vec<Position>,
+        direction: Option<Direction>,
+        bonuses: &[LetterBonus],
+        topology: &dyn Topology,
+        search: &WordSearch,
+    ) -> Match {
+        let mut breakdown = search
+            .scoring
+            .score(&word, bonuses, search.letters_table, search.golden_word)
+            .expect("unable to generate score of a word in the grid");
+
+        match direction {
+            Some(dir) if topology.is_reversed(dir) => {
+                breakdown.apply_reversed_percent(search.reversed_word_percent)
+            }
+            Some(_) => {}
+            None => breakdown.apply_bent_percent(search.bent_word_percent),
+        }
+
+        Match {
+            word,
+            breakdown,
+            positions,
+            direction,
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        self.breakdown.total
+    }
+
+    /// Positions shared with another match.
+    pub(crate) fn shared_positions<'a>(
+        &'a self,
+        other: &'a Match,
+    ) -> impl Iterator<Item = &'a Position> {
+        self.positions
+            .iter()
+            .filter(|pos| other.positions.contains(pos))
+    }
+
+    /// Whether both matches can be removed from the grid together.
+    pub(crate) fn is_compatible_with(&self, other: &Match, crossword: bool) -> bool {
+        match self.shared_positions(other).count() {
+            0 => true,
+            1 => match (self.direction, other.direction) {
+                (Some(d1), Some(d2)) => crossword && d1.orientation() != d2.orientation(),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Adds the crossing bonus to the matches for each of their letters shared with another match.
+fn add_crossing_bonus(matches: &mut [Match], crossing_bonus: u32) {
+    let crossings: Vec<u32> = matches
+        .iter()
+        .enumerate()
+        .map(|(i, m1)| {
+            matches
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| i != *j)
+                .map(|(_, m2)| m1.shared_positions(m2).count() as u32)
+                .sum()
+        })
+        .collect();
+
+    for (m, crossings) in matches.iter_mut().zip(crossings) {
+        let bonus = crossing_bonus.saturating_mul(crossings);
+        m.breakdown.crossing_bonus = bonus;
+        m.breakdown.total = m.breakdown.total.saturating_add(bonus);
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub(crate) enum Cell {
     Letter(LetterIndex),
     Empty,
+    /// Blocks movements and words, never receives a letter.
+    Wall,
 }
 
 #[doc(hidden)]
@@ -637,9 +1270,17 @@ macro_rules! grid {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
 
     use super::*;
+    use crate::{
+        lexicon::{
+            FRENCH_DICTIONARY, FRENCH_LETTERS_TABLE, LETTER_INDEX_BOMB, LETTER_INDEX_WILDCARD,
+            LexiconError,
+        },
+        scoring::ClassicScoring,
+        topology::HexTopology,
+    };
 
     #[test]
     fn grid_macro_works() -> Result<(), GridError> {
@@ -651,17 +1292,17 @@ mod tests {
 
         assert_eq!(
             grid.cell(&Position::new(0 as u8, 0 as u8)),
-            &Cell::Letter(FRENCH_LETTERS_TABLE.try_get_letter_index('Y').unwrap())
+            &Cell::Letter(FRENCH_LETTERS_TABLE.try_get_letter_index("Y").unwrap())
         );
 
         assert_eq!(
             grid.cell(&Position::new(3 as u8, 0 as u8)),
-            &Cell::Letter(FRENCH_LETTERS_TABLE.try_get_letter_index('N').unwrap())
+            &Cell::Letter(FRENCH_LETTERS_TABLE.try_get_letter_index("N").unwrap())
         );
 
         assert_eq!(
             grid.cell(&Position::new(3 as u8, 2 as u8)),
-            &Cell::Letter(FRENCH_LETTERS_TABLE.try_get_letter_index('O').unwrap())
+            &Cell::Letter(FRENCH_LETTERS_TABLE.try_get_letter_index("O").unwrap())
         );
 
         assert_eq!(grid.cell(&Position::new(1 as u8, 0 as u8)), &Cell::Empty);
@@ -670,4 +1311,704 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn direction_between_adjacent_positions() {
+        let from = Position::new(1, 1);
+
+        assert_eq!(
+            Direction::between(&from, &Position::new(1, 0)),
+            Some(Direction::N)
+        );
+        assert_eq!(
+            Direction::between(&from, &Position::new(2, 1)),
+            Some(Direction::E)
+        );
+        assert_eq!(
+            Direction::between(&from, &Position::new(1, 2)),
+            Some(Direction::S)
+        );
+        assert_eq!(
+            Direction::between(&from, &Position::new(0, 1)),
+            Some(Direction::O)
+        );
+        assert_eq!(Direction::between(&from, &Position::new(3, 1)), None);
+        assert_eq!(Direction::between(&from, &from), None);
+    }
+
+    /// Search of the classic game, shared by the tests of the modules searching words.
+    pub(crate) fn classic_search(golden_word: &Word) -> WordSearch<'_> {
+        WordSearch {
+            dictionary: &FRENCH_DICTIONARY,
+            letters_table: &FRENCH_LETTERS_TABLE,
+            golden_word,
+            scoring: &ClassicScoring,
+            selection: MatchSelection::Greedy,
+            crossword: false,
+            crossing_bonus: 0,
+            reading_directions: ReadingDirections::Orthogonal,
+            reversed_word_percent: 100,
+            word_shape: WordShape::Straight,
+            bent_word_percent: 100,
+        }
+    }
+
+    struct FlatScoring;
+
+    impl ScoringRule for FlatScoring {
+        fn score(
+            &self,
+            word: &Word,
+            _bonuses: &[LetterBonus],
+            _letters_table: &LettersTable,
+            _golden_word: &Word,
+        ) -> Result<ScoreBreakdown, LexiconError> {
+            Ok(ScoreBreakdown {
+                letter_scores: vec![0; word.length()],
+                letter_multipliers: vec![1; word.length()],
+                length_multiplier: 1,
+                word_multiplier: 1,
+                golden_bonus: 0,
+                crossing_bonus: 0,
+                reversed_percent: 100,
+                bent_percent: 100,
+                total: 1,
+            })
+        }
+    }
+
+    #[test]
+    fn retrieved_words_are_scored_with_the_given_rule() -> Result<(), GridError> {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )?;
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        let matches = grid.retrieve_words(&WordSearch {
+            scoring: &FlatScoring,
+            ..classic_search(&golden_word)
+        });
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].word,
+            FRENCH_LETTERS_TABLE.parse_word("MAISON").unwrap()
+        );
+        assert_eq!(matches[0].direction, Some(Direction::E));
+        assert_eq!(matches[0].score(), 1);
+        assert_eq!(grid.cell(&Position::new(0, 0)), &Cell::Empty);
+
+        Ok(())
+    }
+
+    /// Words of the grid removed with the given selection strategy.
+    fn selected_words(selection: MatchSelection) -> Vec<Word> {
+        let grid = grid!(
+            ['B', 'O', 'M', 'B', 'Y', 'X']
+            ['I', ' ', ' ', ' ', ' ', 'Y']
+            ['N', ' ', ' ', ' ', ' ', 'L']
+            ['E', ' ', ' ', ' ', ' ', 'E']
+            ['N', ' ', ' ', ' ', ' ', 'N']
+            ['T', ' ', ' ', ' ', ' ', 'E']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        grid.get_words(&WordSearch {
+            selection,
+            ..classic_search(&golden_word)
+        })
+        .into_iter()
+        .map(|m| m.word)
+        .collect()
+    }
+
+    #[test]
+    fn greedy_selection_keeps_the_best_word_first() {
+        let words = selected_words(MatchSelection::Greedy);
+
+        // BOMBYX (82) crosses both BINENT (38) and XYLENE (54)
+        assert_eq!(
+            words,
+            vec![FRENCH_LETTERS_TABLE.parse_word("BOMBYX").unwrap()]
+        );
+    }
+
+    #[test]
+    fn optimal_selection_maximizes_the_total_score() {
+        let words = selected_words(MatchSelection::Optimal);
+
+        assert_eq!(
+            words,
+            vec![
+                FRENCH_LETTERS_TABLE.parse_word("XYLENE").unwrap(),
+                FRENCH_LETTERS_TABLE.parse_word("BINENT").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn crossing_words_share_their_letter_in_crossword_mode() {
+        let grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            ['A', ' ', ' ', ' ', ' ', ' ']
+            ['D', ' ', ' ', ' ', ' ', ' ']
+            ['A', ' ', ' ', ' ', ' ', ' ']
+            ['M', ' ', ' ', ' ', ' ', ' ']
+            ['E', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        let search = |crossword| WordSearch {
+            crossword,
+            crossing_bonus: 10,
+            ..classic_search(&golden_word)
+        };
+
+        let matches = grid.get_words(&search(false));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].breakdown.crossing_bonus, 0);
+
+        let matches = grid.get_words(&search(true));
+        let words: Vec<(Word, u32)> = matches
+            .into_iter()
+            .map(|m| (m.word.clone(), m.score()))
+            .collect();
+        assert_eq!(
+            words,
+            vec![
+                (FRENCH_LETTERS_TABLE.parse_word("MADAME").unwrap(), 42 + 10),
+                (FRENCH_LETTERS_TABLE.parse_word("MAISON").unwrap(), 34 + 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn best_word_of_a_run_is_selected() {
+        let grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N', 'S']
+            [' ', ' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        let matches = grid.get_words(&classic_search(&golden_word));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].word,
+            FRENCH_LETTERS_TABLE.parse_word("MAISONS").unwrap()
+        );
+
+        // the rare letters of ERAYEZ outweigh the length of ARASERA, which starts first
+        let grid = grid!(
+            ['A', 'R', 'A', 'S', 'E', 'R', 'A', 'Y', 'E', 'Z']
+            [' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+
+        for selection in [MatchSelection::Greedy, MatchSelection::Optimal] {
+            let matches = grid.get_words(&WordSearch {
+                selection,
+                ..classic_search(&golden_word)
+            });
+
+            assert_eq!(matches.len(), 1);
+            assert_eq!(
+                matches[0].word,
+                FRENCH_LETTERS_TABLE.parse_word("ERAYEZ").unwrap()
+            );
+            assert_eq!(matches[0].positions.first(), Some(&Position::new(4, 0)));
+        }
+    }
+
+    #[test]
+    fn golden_word_is_never_masked_by_a_longer_word() {
+        let grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N', 'S']
+            [' ', ' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("MAISON").unwrap();
+
+        for selection in [MatchSelection::Greedy, MatchSelection::Optimal] {
+            let matches = grid.get_words(&WordSearch {
+                selection,
+                ..classic_search(&golden_word)
+            });
+
+            assert_eq!(matches.len(), 1);
+            assert_eq!(matches[0].word, golden_word);
+        }
+    }
+
+    #[test]
+    fn reversed_words_follow_the_reading_rules() {
+        let grid = grid!(
+            ['N', 'O', 'S', 'I', 'A', 'M']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        let matches = grid.get_words(&WordSearch {
+            reading_directions: ReadingDirections::Forward,
+            ..classic_search(&golden_word)
+        });
+        assert!(matches.is_empty());
+
+        let matches = grid.get_words(&WordSearch {
+            reading_directions: ReadingDirections::Orthogonal,
+            reversed_word_percent: 50,
+            ..classic_search(&golden_word)
+        });
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].direction, Some(Direction::O));
+        assert_eq!(matches[0].breakdown.reversed_percent, 50);
+        assert_eq!(matches[0].score(), 34 / 2);
+    }
+
+    #[test]
+    fn diagonal_words_are_read_when_configured() {
+        let grid = grid!(
+            ['M', ' ', ' ', ' ', ' ', ' ']
+            [' ', 'A', ' ', ' ', ' ', ' ']
+            [' ', ' ', 'I', ' ', ' ', ' ']
+            [' ', ' ', ' ', 'S', ' ', ' ']
+            [' ', ' ', ' ', ' ', 'O', ' ']
+            [' ', ' ', ' ', ' ', ' ', 'N']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        let matches = grid.get_words(&classic_search(&golden_word));
+        assert!(matches.is_empty());
+
+        let matches = grid.get_words(&WordSearch {
+            reading_directions: ReadingDirections::All,
+            ..classic_search(&golden_word)
+        });
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].direction, Some(Direction::SE));
+        assert_eq!(matches[0].positions.last(), Some(&Position::new(5, 5)));
+    }
+
+    #[test]
+    fn bent_words_are_read_when_configured() {
+        let grid = grid!(
+            ['M', 'A', 'I', ' ', ' ', ' ']
+            ['N', 'O', 'S', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        let matches = grid.get_words(&classic_search(&golden_word));
+        assert!(matches.is_empty());
+
+        let matches = grid.get_words(&WordSearch {
+            word_shape: WordShape::Bent,
+            bent_word_percent: 50,
+            ..classic_search(&golden_word)
+        });
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].word,
+            FRENCH_LETTERS_TABLE.parse_word("MAISON").unwrap()
+        );
+        assert_eq!(matches[0].direction, None);
+        assert_eq!(matches[0].breakdown.bent_percent, 50);
+        assert_eq!(matches[0].score(), 34 / 2);
+    }
+
+    #[test]
+    fn letters_fall_into_cleared_columns() {
+        let mut grid = grid!(
+            ['A', 'B', ' ']
+            [' ', 'C', ' ']
+            [' ', ' ', 'D']
+        )
+        .unwrap();
+
+        assert!(grid.apply_gravity(&[Position::new(0, 2), Position::new(1, 2)]));
+        let expected = grid!(
+            [' ', ' ', ' ']
+            [' ', 'B', ' ']
+            ['A', 'C', 'D']
+        )
+        .unwrap();
+        assert_eq!(grid.cells(), expected.cells());
+
+        assert!(!grid.apply_gravity(&[Position::new(2, 2)]));
+    }
+
+    #[test]
+    fn walls_block_words() {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        assert_eq!(grid.get_words(&classic_search(&golden_word)).len(), 1);
+
+        grid.apply_layout("...#");
+
+        assert!(grid.get_words(&classic_search(&golden_word)).is_empty());
+    }
+
+    #[test]
+    fn walls_block_movements() {
+        let mut grid = grid!(
+            ['A', ' ', ' ']
+            [' ', ' ', ' ']
+            [' ', ' ', ' ']
+        )
+        .unwrap();
+        grid.apply_layout(".#\n.#\n.#");
+        let from = Position::new(0, 0);
+        let wall = Position::new(1, 0);
+
+        assert_eq!(
+            grid.allowed_moving_positions(from),
+            HashSet::from([from, Position::new(0, 1), Position::new(0, 2)])
+        );
+        assert_eq!(grid.most_direct_path(&from, &Position::new(2, 0)), None);
+        assert_eq!(grid.move_cell(wall, from), MoveResult::NoPath);
+        assert_eq!(grid.cell(&wall), &Cell::Wall);
+    }
+
+    #[test]
+    fn holes_are_not_part_of_the_board() {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        // spaces leave the cells untouched, as in the layouts written before holes
+        grid.apply_layout("      \n      ");
+        assert_eq!(grid.cells().len(), 36);
+
+        grid.apply_layout("......\n..x...");
+
+        assert_eq!(grid.get_words(&classic_search(&golden_word)).len(), 1);
+        assert!(!grid.cells().contains_key(&Position::new(2, 1)));
+        assert!(
+            !grid
+                .allowed_moving_positions(Position::new(1, 0))
+                .contains(&Position::new(2, 1))
+        );
+
+        grid.apply_layout("...xx");
+
+        assert!(grid.get_words(&classic_search(&golden_word)).is_empty());
+        assert_eq!(grid.letters().count(), 4);
+    }
+
+    #[test]
+    fn board_shapes_remove_cells() {
+        let cells = |shape: BoardShape| {
+            let mut grid = Grid::empty(6, 6);
+            grid.apply_shape(shape);
+            grid.cells().len()
+        };
+
+        assert_eq!(cells(BoardShape::Rectangle), 36);
+        assert_eq!(cells(BoardShape::Plus), 20);
+        assert_eq!(cells(BoardShape::Ring), 32);
+        assert_eq!(cells(BoardShape::Diamond), 24);
+        assert_eq!(cells(BoardShape::Holes), 32);
+    }
+
+    #[test]
+    fn words_are_read_around_the_torus() {
+        let mut grid = grid!(
+            ['O', 'N', 'M', 'A', 'I', 'S']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        assert!(grid.get_words(&classic_search(&golden_word)).is_empty());
+
+        grid.set_wrap_around(true);
+
+        let matches = grid.get_words(&classic_search(&golden_word));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].positions.first(), Some(&Position::new(2, 0)));
+        assert_eq!(matches[0].positions.last(), Some(&Position::new(1, 0)));
+    }
+
+    #[test]
+    fn movements_go_around_the_torus() {
+        let mut grid = grid!(
+            ['A', ' ', ' ']
+            [' ', ' ', ' ']
+            [' ', ' ', ' ']
+        )
+        .unwrap();
+        grid.apply_layout(".#\n.#\n.#");
+        let from = Position::new(0, 0);
+        let to = Position::new(2, 0);
+        assert_eq!(grid.most_direct_path(&from, &to), None);
+
+        grid.set_wrap_around(true);
+
+        assert_eq!(grid.most_direct_path(&from, &to), Some(vec![from, to]));
+        assert_eq!(grid.direction_between(&from, &to), Some(Direction::O));
+    }
+
+    #[test]
+    fn words_are_read_along_the_three_axes_of_hexagons() {
+        let mut grid = grid!(
+            [' ', ' ', ' ', ' ', ' ', 'N']
+            [' ', ' ', ' ', ' ', 'O', ' ']
+            [' ', ' ', ' ', 'S', ' ', ' ']
+            [' ', ' ', 'I', ' ', ' ', ' ']
+            [' ', 'A', ' ', ' ', ' ', ' ']
+            ['M', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        assert!(grid.get_words(&classic_search(&golden_word)).is_empty());
+
+        grid.set_topology(&HexTopology);
+
+        let matches = grid.get_words(&classic_search(&golden_word));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].direction, Some(Direction::NE));
+
+        // the third axis is read forward, from bottom left to top right
+        let matches = grid.get_words(&WordSearch {
+            reading_directions: ReadingDirections::Forward,
+            reversed_word_percent: 50,
+            ..classic_search(&golden_word)
+        });
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].breakdown.reversed_percent, 100);
+
+        // the other diagonal is not an axis of the hexagons
+        let mut grid = grid!(
+            ['M', ' ', ' ', ' ', ' ', ' ']
+            [' ', 'A', ' ', ' ', ' ', ' ']
+            [' ', ' ', 'I', ' ', ' ', ' ']
+            [' ', ' ', ' ', 'S', ' ', ' ']
+            [' ', ' ', ' ', ' ', 'O', ' ']
+            [' ', ' ', ' ', ' ', ' ', 'N']
+        )
+        .unwrap();
+        grid.set_topology(&HexTopology);
+        let matches = grid.get_words(&WordSearch {
+            reading_directions: ReadingDirections::All,
+            ..classic_search(&golden_word)
+        });
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn neighbour_hexagons_are_drawn_one_cell_apart() {
+        let pos = Position::new(2, 2);
+        let (x, y) = HexTopology.center(&pos);
+
+        for dir in HexTopology.neighbour_directions() {
+            let neighbour = MaybePosition::new(&pos, &dir);
+            let (nx, ny) = HexTopology.center(&Position::new(neighbour.x as u8, neighbour.y as u8));
+            assert!(((nx - x).hypot(ny - y) - 1.0).abs() < 1e-4, "{dir:?}");
+        }
+    }
+
+    #[test]
+    fn hexagons_have_six_neighbours() {
+        let mut grid = Grid::empty(3, 3);
+        grid.set_topology(&HexTopology);
+        let center = Position::new(1, 1);
+
+        let neighbours = grid.neighbours(&center);
+
+        assert_eq!(neighbours.len(), 6);
+        assert!(!neighbours.contains(&Position::new(0, 0)));
+        assert!(!neighbours.contains(&Position::new(2, 2)));
+
+        // there is no diagonal to move along
+        grid.set_movement_directions(MovementDirections::All);
+        assert_eq!(
+            grid.most_direct_path(&center, &Position::new(2, 2))
+                .map(|path| path.len()),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn premiums_are_consumed_unless_permanent() {
+        let board = || {
+            let mut grid = grid!(
+                ['M', 'A', 'I', 'S', 'O', 'N']
+                [' ', ' ', ' ', ' ', ' ', ' ']
+            )
+            .unwrap();
+            grid.apply_layout("T..d");
+            grid
+        };
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        let corner = Position::new(0, 0);
+
+        let mut grid = board();
+        let matches = grid.retrieve_words(&classic_search(&golden_word));
+        assert_eq!(matches[0].breakdown.word_multiplier, 3);
+        assert_eq!(
+            matches[0].breakdown.letter_multipliers,
+            vec![1, 1, 1, 2, 1, 1]
+        );
+        // letters sum to 17, S scores 2 and is doubled, the 6 letters word is doubled
+        assert_eq!(matches[0].score(), (17 + 2) * 2 * 3);
+        assert_eq!(grid.premium(&corner), None);
+
+        let mut grid = board();
+        grid.set_premium_lifetime(PremiumLifetime::Permanent);
+        grid.retrieve_words(&classic_search(&golden_word));
+        assert_eq!(grid.premium(&corner), Some(Premium::TripleWord));
+    }
+
+    #[test]
+    fn special_tiles_are_read_in_words() {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        let a = FRENCH_LETTERS_TABLE.try_get_letter_index("A").unwrap();
+        grid.update_cell(Position::new(1, 0), Cell::Letter(a.golden()));
+        grid.update_cell(Position::new(4, 0), Cell::Letter(LETTER_INDEX_WILDCARD));
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+
+        let matches = grid.get_words(&classic_search(&golden_word));
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].word,
+            FRENCH_LETTERS_TABLE.parse_word("MAISON").unwrap()
+        );
+        assert_eq!(matches[0].breakdown.letter_scores[4], 0);
+        assert_eq!(matches[0].breakdown.word_multiplier, 2);
+
+        grid.update_cell(Position::new(2, 0), Cell::Letter(LETTER_INDEX_BOMB));
+        assert!(grid.get_words(&classic_search(&golden_word)).is_empty());
+    }
+
+    #[test]
+    fn wildcards_stand_for_the_golden_word_first() {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        grid.update_cell(Position::new(0, 0), Cell::Letter(LETTER_INDEX_WILDCARD));
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("RAISON").unwrap();
+
+        let matches = grid.get_words(&classic_search(&golden_word));
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].word, golden_word);
+        assert!(matches[0].breakdown.golden_bonus > 0);
+    }
+
+    #[test]
+    fn bombs_clear_their_neighbourhood() {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S']
+            ['M', 'A', 'I', 'S']
+            ['M', 'A', 'I', 'S']
+            ['M', 'A', 'I', 'S']
+        )
+        .unwrap();
+        grid.update_cell(Position::new(1, 1), Cell::Letter(LETTER_INDEX_BOMB));
+        grid.apply_layout("..#");
+
+        let cleared = grid.explode(Position::new(1, 1));
+
+        assert_eq!(cleared.len(), 8);
+        assert_eq!(grid.cell(&Position::new(2, 0)), &Cell::Wall);
+        assert_eq!(grid.empty_cells().count(), 8);
+    }
+
+    #[test]
+    fn unused_letters_turn_to_stone_until_a_word_is_cleared_next_to_them() {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', ' ']
+            ['Z', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        grid.set_lock_age(Some(2), true);
+        let stone = Position::new(0, 1);
+        grid.age_letters();
+        grid.age_letters();
+
+        assert!(grid.is_locked(&stone));
+        assert_eq!(
+            grid.move_cell(stone, Position::new(1, 1)),
+            MoveResult::Locked
+        );
+
+        let n = FRENCH_LETTERS_TABLE.try_get_letter_index("N").unwrap();
+        grid.update_cell(Position::new(5, 0), Cell::Letter(n));
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        assert_eq!(grid.retrieve_words(&classic_search(&golden_word)).len(), 1);
+
+        assert!(!grid.is_locked(&stone));
+        assert_eq!(
+            grid.move_cell(stone, Position::new(1, 1)),
+            MoveResult::Moved
+        );
+    }
+
+    #[test]
+    fn only_letters_turn_to_stone() {
+        let mut grid = grid!(
+            ['A', ' ', ' ']
+            [' ', ' ', ' ']
+            [' ', ' ', ' ']
+        )
+        .unwrap();
+        grid.apply_layout("..#");
+        grid.set_lock_age(Some(0), true);
+
+        assert!(grid.is_locked(&Position::new(0, 0)));
+        assert!(!grid.is_locked(&Position::new(1, 0)));
+        assert!(!grid.is_locked(&Position::new(2, 0)));
+    }
+
+    #[test]
+    fn letters_turned_to_stone_can_stop_forming_words() {
+        let mut grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', 'N']
+            [' ', ' ', ' ', ' ', ' ', ' ']
+        )
+        .unwrap();
+        grid.set_lock_age(Some(1), false);
+        grid.age_letters();
+
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        assert!(grid.get_words(&classic_search(&golden_word)).is_empty());
+    }
+
+    #[test]
+    fn movements_stay_orthogonal_unless_configured() {
+        let mut grid = grid!(
+            ['A', 'B', ' ']
+            ['C', ' ', ' ']
+            [' ', ' ', ' ']
+        )
+        .unwrap();
+        let from = Position::new(0, 0);
+        let to = Position::new(1, 1);
+
+        assert!(!grid.allowed_moving_positions(from).contains(&to));
+        assert_eq!(grid.most_direct_path(&from, &to), None);
+
+        grid.set_movement_directions(MovementDirections::All);
+
+        assert!(grid.allowed_moving_positions(from).contains(&to));
+        assert_eq!(grid.most_direct_path(&from, &to), Some(vec![from, to]));
+        assert_eq!(grid.move_cell(from, to), MoveResult::Moved);
+    }
 }
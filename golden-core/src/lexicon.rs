@@ -1,23 +1,53 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Debug,
-};
+use std::{collections::HashMap, fmt::Debug};
 
+use itertools::Itertools;
 use lazy_static::lazy_static;
 use rand::{
     Rng,
     seq::{IndexedRandom, SliceRandom},
 };
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+use crate::scoring::checked_sum;
+
 const NUMBER_OF_LETTERS_IN_INITIAL_GRID: usize = 8;
 const NUMBER_OF_LETTERS_TRIPLETS: usize = 64;
-const NUMBER_OF_LETTERS: usize = NUMBER_OF_LETTERS_IN_INITIAL_GRID + NUMBER_OF_LETTERS_TRIPLETS * 3;
+pub const NUMBER_OF_LETTERS: usize =
+    NUMBER_OF_LETTERS_IN_INITIAL_GRID + NUMBER_OF_LETTERS_TRIPLETS * 3;
+
+/// Checks that a pool fills the initial grid then whole triplets, and holds each tile at least
+/// once.
+pub(crate) fn check_pool_size(pool_size: usize, tiles: usize) -> Result<(), LexiconError> {
+    let triplets_letters = pool_size.checked_sub(NUMBER_OF_LETTERS_IN_INITIAL_GRID);
+    if !triplets_letters.is_some_and(|letters| letters > 0 && letters % 3 == 0) {
+        return Err(LexiconError::InvalidPoolSize { pool_size });
+    }
+    if pool_size < tiles {
+        return Err(LexiconError::PoolTooSmall { pool_size, tiles });
+    }
+
+    Ok(())
+}
 
 #[derive(Debug, thiserror::Error, PartialEq)]
-pub(crate) enum LexiconError {
-    #[error("unknown letter '{char}'")]
-    UnknownLetter { char: char },
+pub enum LexiconError {
+    #[error("unknown letter '{letter}'")]
+    UnknownLetter { letter: String },
+    #[error("word '{word}' cannot be split into tiles of the letters table")]
+    UntokenizableWord { word: String },
+    #[error("{tiles} tiles exceed the {MAX_TILES} tiles of a letters table")]
+    TooManyTiles { tiles: usize },
+    #[error("pool of {pool_size} letters cannot hold each of the {tiles} tiles")]
+    PoolTooSmall { pool_size: usize, tiles: usize },
+    #[error(
+        "pool of {pool_size} letters does not fill the {NUMBER_OF_LETTERS_IN_INITIAL_GRID} letters of the initial grid and whole triplets"
+    )]
+    InvalidPoolSize { pool_size: usize },
+    #[error("no playable word in the corpus")]
+    EmptyCorpus,
+    #[error("invalid letters table: {reason}")]
+    InvalidLettersTable { reason: String },
     #[error("unknown letter index '{i}'")]
     UnknownLetterIndex { i: LetterIndex },
     #[error("invalid word length '{len}'")]
@@ -25,19 +55,76 @@ pub(crate) enum LexiconError {
     #[error("letter index out of bounds '{index}'")]
     LetterIndexOutOfBounds { index: u8 },
     #[error(
-        "unexpected number of letters in letters table: {number_of_letters}, expected {NUMBER_OF_LETTERS}"
+        "unexpected number of letters in letters table: {number_of_letters}, expected {pool_size}"
     )]
-    UnexpectedNumberOfLettersInLettersTable { number_of_letters: usize },
+    UnexpectedNumberOfLettersInLettersTable {
+        number_of_letters: usize,
+        pool_size: usize,
+    },
     #[error("missing score multiplier during score evaluation of the word of length {len}")]
     MissingScoreMultiplier { len: usize },
+    #[error("score overflow during score evaluation")]
+    ScoreOverflow,
 }
 
 #[wasm_bindgen]
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) struct LetterIndex(pub(crate) u8);
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LetterIndex(pub(crate) u8);
+
+/// Number of tiles a letters table can hold, so that the golden tile of the last one stays below
+/// the special tiles.
+pub const MAX_TILES: usize = (LETTER_INDEX_BOMB.0 - LetterIndex::GOLDEN_FLAG) as usize;
 
 /// Byte representation of an empty cell.
 pub(crate) const LETTER_INDEX_NONE: LetterIndex = LetterIndex(255);
+/// Byte representation of a wildcard tile, standing for any letter.
+pub(crate) const LETTER_INDEX_WILDCARD: LetterIndex = LetterIndex(254);
+/// Byte representation of a bomb tile, clearing its neighbourhood once moved.
+pub(crate) const LETTER_INDEX_BOMB: LetterIndex = LetterIndex(253);
+
+/// Kind of tile held by a letter index.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TileKind {
+    /// A plain letter of the letters table.
+    Letter,
+    /// Stands for any letter, worth nothing.
+    Wildcard,
+    /// Not a letter, clears its neighbourhood once moved.
+    Bomb,
+    /// A letter doubling the score of its word.
+    Golden,
+}
+
+impl LetterIndex {
+    /// Flag set on the index of the letter of a golden tile.
+    const GOLDEN_FLAG: u8 = 0x80;
+
+    pub(crate) fn tile_kind(self) -> TileKind {
+        match self {
+            LETTER_INDEX_WILDCARD => TileKind::Wildcard,
+            LETTER_INDEX_BOMB => TileKind::Bomb,
+            LetterIndex(i) if i & Self::GOLDEN_FLAG != 0 && self < LETTER_INDEX_BOMB => {
+                TileKind::Golden
+            }
+            _ => TileKind::Letter,
+        }
+    }
+
+    /// Golden tile of the letter.
+    pub(crate) fn golden(self) -> LetterIndex {
+        LetterIndex(self.0 | Self::GOLDEN_FLAG)
+    }
+
+    /// Letter of the tile, without the golden flag.
+    pub(crate) fn plain(self) -> LetterIndex {
+        match self.tile_kind() {
+            TileKind::Golden => LetterIndex(self.0 & !Self::GOLDEN_FLAG),
+            _ => self,
+        }
+    }
+}
 
 impl From<u8> for LetterIndex {
     fn from(value: u8) -> Self {
@@ -65,7 +152,7 @@ impl std::fmt::Display for LetterIndex {
 
 /// Representation of a word in the game.
 #[wasm_bindgen]
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Word {
     letters: Vec<LetterIndex>,
 }
@@ -92,36 +179,6 @@ impl Word {
 
         Ok(Word { letters })
     }
-
-    pub(crate) fn score(
-        &self,
-        letter_table: &LettersTable,
-        golden_word: &Word,
-    ) -> Result<u16, LexiconError> {
-        let letters_score: Result<u16, LexiconError> = self
-            .letters
-            .iter()
-            .map(|index| Ok(letter_table.try_get_letter(*index)?.score as u16))
-            .sum();
-
-        let multiplier: u16 = match self.length() {
-            5 => 1,
-            6 => 2,
-            7 => 3,
-            8 => 4,
-            _ => {
-                return Err(LexiconError::MissingScoreMultiplier { len: self.length() });
-            }
-        };
-
-        let letters_score = letters_score? * multiplier;
-
-        if self == golden_word {
-            return Ok(100 + letters_score);
-        };
-
-        Ok(letters_score)
-    }
 }
 
 /// Index of letters used in the game.
@@ -129,64 +186,102 @@ impl Word {
 #[derive(Clone)]
 pub struct LettersTable {
     letters: Vec<LetterConfig>,
-    letter_index_by_char: HashMap<char, LetterIndex>,
+    tokenizer: Tokenizer,
+    /// Number of letters drawn for a game, the initial grid then the triplets.
+    pool_size: usize,
 }
 
 impl LettersTable {
-    pub(crate) fn new(letters: Vec<LetterConfig>) -> Result<LettersTable, LexiconError> {
+    /// Creates a table drawing pools of `pool_size` letters, the repartitions of the letters
+    /// adding up to the pool size.
+    pub(crate) fn new(
+        letters: Vec<LetterConfig>,
+        pool_size: usize,
+    ) -> Result<LettersTable, LexiconError> {
+        check_pool_size(pool_size, letters.len())?;
+
         // check that the letters table contains the expected number of letters
         let repartition_sum: usize = letters.iter().map(|lc| lc.repartition).sum();
-        if repartition_sum != NUMBER_OF_LETTERS_IN_INITIAL_GRID + NUMBER_OF_LETTERS_TRIPLETS * 3 {
+        if repartition_sum != pool_size {
             return Err(LexiconError::UnexpectedNumberOfLettersInLettersTable {
                 number_of_letters: repartition_sum,
+                pool_size,
             });
         }
 
-        let mut letter_index_by_char = HashMap::new();
-
-        for (index, letter_config) in letters.iter().enumerate() {
-            letter_index_by_char.insert(letter_config.letter, LetterIndex::from(index as u8));
-        }
+        let tokenizer = Tokenizer::new(letters.iter().map(|lc| lc.letter.as_str()))?;
 
         Ok(LettersTable {
             letters,
-            letter_index_by_char,
+            tokenizer,
+            pool_size,
         })
     }
 
+    /// Reads a letters table serialized as a JSON list of letters, drawing pools of `pool_size`
+    /// letters.
+    pub fn from_json(json: &str, pool_size: usize) -> Result<LettersTable, LexiconError> {
+        let letters =
+            serde_json::from_str(json).map_err(|e| LexiconError::InvalidLettersTable {
+                reason: e.to_string(),
+            })?;
+
+        LettersTable::new(letters, pool_size)
+    }
+
     pub(crate) fn try_get_letter(&self, index: LetterIndex) -> Result<&LetterConfig, LexiconError> {
         self.letters
             .get(index.0 as usize)
             .ok_or(LexiconError::UnknownLetterIndex { i: index })
     }
 
-    pub(crate) fn try_get_letter_index(&self, char: char) -> Result<LetterIndex, LexiconError> {
-        self.letter_index_by_char
-            .get(&char)
-            .copied()
-            .ok_or(LexiconError::UnknownLetter { char })
+    #[cfg(test)]
+    pub(crate) fn try_get_letter_index(&self, tile: &str) -> Result<LetterIndex, LexiconError> {
+        tile.chars()
+            .next()
+            .and_then(|first_char| self.tokenizer.tiles_by_first_char.get(&first_char))
+            .and_then(|tiles| tiles.iter().find(|(t, _)| t == tile))
+            .map(|(_, index)| *index)
+            .ok_or_else(|| LexiconError::UnknownLetter {
+                letter: tile.to_string(),
+            })
     }
 
-    pub(crate) fn parse_word(&self, word_str: &str) -> Result<Word, LexiconError> {
-        let mut letters = Vec::with_capacity(word_str.len());
-        for c in word_str.chars() {
-            let index = self.try_get_letter_index(c)?;
-            letters.push(index);
+    /// Letter shown by a tile, golden tiles showing their plain letter. Special tiles have no
+    /// letter.
+    pub(crate) fn tile_letter(&self, index: LetterIndex) -> Option<&LetterConfig> {
+        match index.tile_kind() {
+            TileKind::Wildcard | TileKind::Bomb => None,
+            TileKind::Letter | TileKind::Golden => self.try_get_letter(index.plain()).ok(),
         }
+    }
+
+    /// Parses a word made of the tiles of the table, its length being its number of tiles.
+    pub(crate) fn parse_word(&self, word_str: &str) -> Result<Word, LexiconError> {
+        Word::new(self.tokenize(word_str)?)
+    }
 
-        Word::new(letters)
+    /// Splits a word into the tiles of the table.
+    pub(crate) fn tokenize(&self, word_str: &str) -> Result<Vec<LetterIndex>, LexiconError> {
+        self.tokenizer.tokenize(word_str)
     }
 
     pub(crate) fn score_word(&self, word: &Word) -> Result<u32, LexiconError> {
+        checked_sum(self.letter_scores(word)?).ok_or(LexiconError::ScoreOverflow)
+    }
+
+    /// Score of each letter of the word, in reading order.
+    pub(crate) fn letter_scores(&self, word: &Word) -> Result<Vec<u32>, LexiconError> {
         word.letters
             .iter()
             .map(|&index| {
                 self.try_get_letter(index)
                     .map(|letter_config| letter_config.score)
             })
-            .sum()
+            .collect()
     }
 
+    /// Shuffles the letters of a pool, filling the initial grid then the triplets.
     pub(crate) fn generate_letters_pool<R>(&self, rng: &mut R) -> LettersPool
     where
         R: Rng + ?Sized,
@@ -208,7 +303,7 @@ impl LettersTable {
 
         let triplets = all_letters
             .chunks(3)
-            .take(NUMBER_OF_LETTERS_TRIPLETS)
+            .take((self.pool_size - NUMBER_OF_LETTERS_IN_INITIAL_GRID) / 3)
             .map(|chunk| (chunk[0], chunk[1], chunk[2]))
             .collect();
 
@@ -221,163 +316,243 @@ impl LettersTable {
 
 #[wasm_bindgen]
 impl LettersTable {
-    pub fn try_get_letter_score(&self, index: u8) -> u32 {
-        let i = LetterIndex::try_from(index).unwrap();
-        let lc = self.try_get_letter(i).unwrap();
-        lc.score
+    /// Score of the letter of a tile, `None` for special tiles and unknown indexes.
+    pub fn try_get_letter_score(&self, index: u8) -> Option<u32> {
+        self.tile_letter(LetterIndex::from(index))
+            .map(|lc| lc.score)
     }
 
-    pub fn try_get_letter_char(&self, index: u8) -> char {
-        let i = LetterIndex::try_from(index).unwrap();
-        let lc = self.try_get_letter(i).unwrap();
-        lc.letter
+    /// Characters of the letter of a tile, `None` for special tiles and unknown indexes.
+    pub fn try_get_letter_char(&self, index: u8) -> Option<String> {
+        self.tile_letter(LetterIndex::from(index))
+            .map(|lc| lc.letter.clone())
     }
 }
 
-/// Index of letters used in the game.
+/// Splits words into tiles, a tile being one or several characters.
 #[derive(Clone)]
+pub(crate) struct Tokenizer {
+    /// Tiles starting with each character, the longest ones first.
+    tiles_by_first_char: HashMap<char, Vec<(String, LetterIndex)>>,
+}
+
+impl Tokenizer {
+    /// Creates a tokenizer of the given tiles, indexed in order.
+    pub(crate) fn new<'a>(
+        tiles: impl ExactSizeIterator<Item = &'a str>,
+    ) -> Result<Tokenizer, LexiconError> {
+        if tiles.len() > MAX_TILES {
+            return Err(LexiconError::TooManyTiles { tiles: tiles.len() });
+        }
+
+        let mut tiles_by_first_char: HashMap<char, Vec<(String, LetterIndex)>> = HashMap::new();
+
+        for (index, tile) in tiles.enumerate() {
+            if let Some(first_char) = tile.chars().next() {
+                tiles_by_first_char
+                    .entry(first_char)
+                    .or_default()
+                    .push((tile.to_string(), LetterIndex::from(index as u8)));
+            }
+        }
+
+        for tiles in tiles_by_first_char.values_mut() {
+            tiles.sort_by_key(|(tile, _)| std::cmp::Reverse(tile.len()));
+        }
+
+        Ok(Tokenizer {
+            tiles_by_first_char,
+        })
+    }
+
+    /// Splits a word into tiles, preferring the longest tiles and backtracking when the rest of
+    /// the word cannot be split anymore.
+    pub(crate) fn tokenize(&self, word_str: &str) -> Result<Vec<LetterIndex>, LexiconError> {
+        let mut tiles = Vec::with_capacity(word_str.len());
+        if !self.tokenize_from(word_str, &mut tiles) {
+            return Err(LexiconError::UntokenizableWord {
+                word: word_str.to_string(),
+            });
+        }
+
+        Ok(tiles)
+    }
+
+    fn tokenize_from(&self, rest: &str, tiles: &mut Vec<LetterIndex>) -> bool {
+        let Some(first_char) = rest.chars().next() else {
+            return true;
+        };
+
+        let candidates = self
+            .tiles_by_first_char
+            .get(&first_char)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        for (tile, index) in candidates {
+            if rest.starts_with(tile.as_str()) {
+                tiles.push(*index);
+                if self.tokenize_from(&rest[tile.len()..], tiles) {
+                    return true;
+                }
+                tiles.pop();
+            }
+        }
+
+        false
+    }
+}
+
+/// Index of letters used in the game.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LetterConfig {
-    pub letter: char,
+    /// Characters of the tile, a digraph such as `QU` counting as a single tile.
+    pub letter: String,
     pub repartition: usize,
     pub score: u32,
 }
 
 lazy_static! {
+    /// Hand-tuned table of the game. `letters/french1.json`, derived from the wordlist, keeps a
+    /// standalone Q and orders its tiles differently, so switching to it would change the
+    /// letter indexes and the scores of every game.
     pub static ref FRENCH_LETTERS_TABLE: LettersTable = {
         let letters = vec![
             LetterConfig {
-                letter: 'A',
+                letter: "A".to_string(),
                 repartition: 23,
                 score: 1,
             },
             LetterConfig {
-                letter: 'B',
+                letter: "B".to_string(),
                 repartition: 4,
                 score: 7,
             },
             LetterConfig {
-                letter: 'C',
+                letter: "C".to_string(),
                 repartition: 7,
                 score: 5,
             },
             LetterConfig {
-                letter: 'D',
+                letter: "D".to_string(),
                 repartition: 5,
                 score: 6,
             },
             LetterConfig {
-                letter: 'E',
+                letter: "E".to_string(),
                 repartition: 30,
                 score: 1,
             },
             LetterConfig {
-                letter: 'F',
+                letter: "F".to_string(),
                 repartition: 3,
                 score: 8,
             },
             LetterConfig {
-                letter: 'G',
+                letter: "G".to_string(),
                 repartition: 4,
                 score: 7,
             },
             LetterConfig {
-                letter: 'H',
+                letter: "H".to_string(),
                 repartition: 3,
                 score: 8,
             },
             LetterConfig {
-                letter: 'I',
+                letter: "I".to_string(),
                 repartition: 16,
                 score: 2,
             },
             LetterConfig {
-                letter: 'J',
+                letter: "J".to_string(),
                 repartition: 1,
                 score: 9,
             },
             LetterConfig {
-                letter: 'K',
+                letter: "K".to_string(),
                 repartition: 1,
                 score: 9,
             },
             LetterConfig {
-                letter: 'L',
+                letter: "L".to_string(),
                 repartition: 9,
                 score: 4,
             },
             LetterConfig {
-                letter: 'M',
+                letter: "M".to_string(),
                 repartition: 5,
                 score: 6,
             },
             LetterConfig {
-                letter: 'N',
+                letter: "N".to_string(),
                 repartition: 10,
                 score: 3,
             },
             LetterConfig {
-                letter: 'O',
+                letter: "O".to_string(),
                 repartition: 11,
                 score: 3,
             },
             LetterConfig {
-                letter: 'P',
+                letter: "P".to_string(),
                 repartition: 5,
                 score: 6,
             },
+            // a lone Q hardly ever forms a french word
             LetterConfig {
-                letter: 'Q',
+                letter: "QU".to_string(),
                 repartition: 1,
                 score: 9,
             },
             LetterConfig {
-                letter: 'R',
+                letter: "R".to_string(),
                 repartition: 15,
                 score: 2,
             },
             LetterConfig {
-                letter: 'S',
+                letter: "S".to_string(),
                 repartition: 17,
                 score: 2,
             },
             LetterConfig {
-                letter: 'T',
+                letter: "T".to_string(),
                 repartition: 13,
                 score: 3,
             },
             LetterConfig {
-                letter: 'U',
+                letter: "U".to_string(),
                 repartition: 9,
                 score: 4,
             },
             LetterConfig {
-                letter: 'V',
+                letter: "V".to_string(),
                 repartition: 3,
                 score: 8,
             },
             LetterConfig {
-                letter: 'W',
+                letter: "W".to_string(),
                 repartition: 1,
                 score: 9,
             },
             LetterConfig {
-                letter: 'X',
+                letter: "X".to_string(),
                 repartition: 1,
                 score: 9,
             },
             LetterConfig {
-                letter: 'Y',
+                letter: "Y".to_string(),
                 repartition: 1,
                 score: 9,
             },
             LetterConfig {
-                letter: 'Z',
+                letter: "Z".to_string(),
                 repartition: 2,
                 score: 9,
             },
         ];
 
-        LettersTable::new(letters).expect("Unable to generate letters table for french")
+        LettersTable::new(letters, NUMBER_OF_LETTERS)
+            .expect("Unable to generate letters table for french")
     };
 }
 
@@ -389,14 +564,17 @@ pub(crate) struct LettersPool {
 
 /// Dictionary for word validation using LetterIndex representation
 pub struct Dictionary {
-    words: HashSet<Word>,
+    /// Sorted words, allowing to look for words and prefixes of words.
+    words: Vec<Word>,
+    /// Letters used by the words, replacing wildcards.
+    alphabet: Vec<LetterIndex>,
     six_letter_words: Vec<Word>,
 }
 
 impl Dictionary {
     /// Create a new dictionary from a word list and letter table
     pub fn new(wordlist_content: &str, letters_table: &LettersTable) -> Self {
-        let mut words: HashSet<Word> = HashSet::new();
+        let mut words: Vec<Word> = Vec::new();
         let mut six_letter_words: Vec<Word> = Vec::new();
 
         for line in wordlist_content.lines() {
@@ -412,19 +590,110 @@ impl Dictionary {
                     six_letter_words.push(word.clone());
                 }
 
-                words.insert(word);
+                words.push(word);
             }
         }
 
+        words.sort();
+        words.dedup();
+
+        let alphabet = words
+            .iter()
+            .flat_map(|word| word.letters.iter().copied())
+            .sorted()
+            .dedup()
+            .collect();
+
         Dictionary {
             words,
+            alphabet,
             six_letter_words,
         }
     }
 
     /// Check if a Word exists in the dictionary
     pub fn contains(&self, word: &Word) -> bool {
-        self.words.contains(&word)
+        self.words.binary_search(word).is_ok()
+    }
+
+    /// Finds the word of the dictionary made of the given letters, a wildcard standing for any
+    /// letter. The golden word is preferred when the wildcards can stand for its letters, so that
+    /// it is never masked by another word.
+    pub(crate) fn find(&self, letters: &[LetterIndex], golden_word: &Word) -> Option<Word> {
+        if !letters.contains(&LETTER_INDEX_WILDCARD) {
+            let word = Word::new(letters.to_vec()).ok()?;
+            return self.contains(&word).then_some(word);
+        }
+
+        let is_golden_word = golden_word.letters.len() == letters.len()
+            && letters
+                .iter()
+                .zip(&golden_word.letters)
+                .all(|(&letter, &golden)| letter == LETTER_INDEX_WILDCARD || letter == golden);
+        if is_golden_word && self.contains(golden_word) {
+            return Some(golden_word.clone());
+        }
+
+        self.resolve_wildcards(&mut Vec::with_capacity(letters.len()), letters, true)
+            .and_then(|letters| Word::new(letters).ok())
+    }
+
+    /// Check if at least one word of the dictionary starts with the given letters, a wildcard
+    /// standing for any letter.
+    pub(crate) fn has_prefix(&self, prefix: &[LetterIndex]) -> bool {
+        if !prefix.contains(&LETTER_INDEX_WILDCARD) {
+            return self.has_plain_prefix(prefix);
+        }
+
+        self.resolve_wildcards(&mut Vec::with_capacity(prefix.len()), prefix, false)
+            .is_some()
+    }
+
+    fn has_plain_prefix(&self, prefix: &[LetterIndex]) -> bool {
+        let index = self
+            .words
+            .partition_point(|word| word.letters.as_slice() < prefix);
+
+        self.words
+            .get(index)
+            .is_some_and(|word| word.letters.starts_with(prefix))
+    }
+
+    /// Replaces the wildcards of the remaining letters with the first letters leading to a word,
+    /// or to the prefix of a word.
+    fn resolve_wildcards(
+        &self,
+        resolved: &mut Vec<LetterIndex>,
+        remaining: &[LetterIndex],
+        whole_word: bool,
+    ) -> Option<Vec<LetterIndex>> {
+        let Some((&letter, remaining)) = remaining.split_first() else {
+            let found =
+                !whole_word || Word::new(resolved.clone()).is_ok_and(|word| self.contains(&word));
+            return found.then(|| resolved.clone());
+        };
+
+        let candidates = if letter == LETTER_INDEX_WILDCARD {
+            self.alphabet.clone()
+        } else {
+            vec![letter]
+        };
+
+        for candidate in candidates {
+            resolved.push(candidate);
+            let found = if self.has_plain_prefix(resolved) {
+                self.resolve_wildcards(resolved, remaining, whole_word)
+            } else {
+                None
+            };
+            resolved.pop();
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        None
     }
 
     pub fn get_random_six_letter_word<R>(&self, rng: &mut R) -> Word
@@ -444,3 +713,80 @@ lazy_static! {
         Dictionary::new(FRENCH_WORDLIST, &FRENCH_LETTERS_TABLE)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digraphs_count_as_a_single_tile() -> Result<(), LexiconError> {
+        let word = FRENCH_LETTERS_TABLE.parse_word("QUITTE")?;
+
+        assert_eq!(word.length(), 5);
+        assert!(FRENCH_DICTIONARY.contains(&word));
+        assert!(FRENCH_LETTERS_TABLE.parse_word("IRAQIEN").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn golden_tiles_of_the_last_tiles_are_not_special_tiles() {
+        let tiles: Vec<String> = (0..=MAX_TILES).map(|i| format!("T{i}")).collect();
+        let last = LetterIndex::from((MAX_TILES - 1) as u8);
+
+        assert_eq!(last.golden().tile_kind(), TileKind::Golden);
+        assert_eq!(last.golden().plain(), last);
+        assert!(Tokenizer::new(tiles[..MAX_TILES].iter().map(String::as_str)).is_ok());
+        assert!(matches!(
+            Tokenizer::new(tiles.iter().map(String::as_str)),
+            Err(LexiconError::TooManyTiles { tiles }) if tiles == MAX_TILES + 1
+        ));
+    }
+
+    #[test]
+    fn tiles_show_the_letter_of_their_plain_tile() {
+        let a = FRENCH_LETTERS_TABLE.try_get_letter_index("A").unwrap();
+
+        assert_eq!(
+            FRENCH_LETTERS_TABLE.try_get_letter_char(a.golden().0),
+            Some("A".to_string())
+        );
+        assert_eq!(
+            FRENCH_LETTERS_TABLE.try_get_letter_score(a.golden().0),
+            FRENCH_LETTERS_TABLE.try_get_letter_score(a.0)
+        );
+        for special in [LETTER_INDEX_WILDCARD, LETTER_INDEX_BOMB, LETTER_INDEX_NONE] {
+            assert_eq!(FRENCH_LETTERS_TABLE.try_get_letter_char(special.0), None);
+            assert_eq!(FRENCH_LETTERS_TABLE.try_get_letter_score(special.0), None);
+        }
+    }
+
+    #[test]
+    fn tokenizer_backtracks_from_the_longest_tiles() -> Result<(), LexiconError> {
+        let tile = |letter: &str, repartition| LetterConfig {
+            letter: letter.to_string(),
+            repartition,
+            score: 1,
+        };
+        let table = LettersTable::new(
+            vec![
+                tile("A", NUMBER_OF_LETTERS - 2),
+                tile("AB", 1),
+                tile("BC", 1),
+            ],
+            NUMBER_OF_LETTERS,
+        )?;
+        let [a, ab, bc] = ["A", "AB", "BC"].map(|t| table.try_get_letter_index(t).unwrap());
+
+        assert_eq!(table.tokenize("ABAB")?, vec![ab, ab]);
+        assert_eq!(table.tokenize("ABC")?, vec![a, bc]);
+        assert_eq!(
+            table.tokenize("ABD"),
+            Err(LexiconError::UntokenizableWord {
+                word: "ABD".to_string()
+            })
+        );
+
+        Ok(())
+    }
+}
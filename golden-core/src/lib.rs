@@ -1,8 +1,16 @@
 pub mod clock;
+pub mod combo;
+pub mod config;
+pub mod corpus;
 pub mod game;
 pub mod grid;
 pub mod lexicon;
 pub mod log;
+pub mod placement;
+pub mod scoring;
+pub mod selection;
+pub mod summary;
+pub mod topology;
 
 use console_error_panic_hook;
 use wasm_bindgen::prelude::*;
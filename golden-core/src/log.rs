@@ -1,27 +1,31 @@
 #[macro_export]
 macro_rules! log {
     ($($t:tt)*) => {
-        web_sys::console::log_1(&format!($($t)*).into())
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::log_1(&format!($($t)*).into());
     };
 }
 
 #[macro_export]
 macro_rules! debug {
     ($($t:tt)*) => {
-        web_sys::console::debug_1(&format!($($t)*).into())
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::debug_1(&format!($($t)*).into());
     };
 }
 
 #[macro_export]
 macro_rules! warn {
     ($($t:tt)*) => {
-        web_sys::console::warn_1(&format!($($t)*).into())
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::warn_1(&format!($($t)*).into());
     };
 }
 
 #[macro_export]
 macro_rules! error {
     ($($t:tt)*) => {
-        web_sys::console::error_1(&format!($($t)*).into())
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::error_1(&format!($($t)*).into());
     };
 }
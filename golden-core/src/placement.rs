@@ -0,0 +1,278 @@
+use std::collections::{HashMap, VecDeque, hash_map::Entry};
+
+use rand::{Rng, seq::IndexedRandom};
+
+use crate::{
+    config::LetterPlacement,
+    grid::{Cell, Grid, GridIndex, Position, WordSearch},
+    lexicon::LetterIndex,
+};
+
+/// Chooses the empty cell receiving a new letter, `None` if the grid is full.
+///
+/// # Arguments
+///
+/// * `strategy` - Placement strategy of the game.
+/// * `grid` - Grid receiving the letter.
+/// * `letter` - Letter to place.
+/// * `drop_column` - Column chosen by the player, used when dropping letters.
+/// * `search` - Rules of the words, used to avoid completing words.
+/// * `rng` - Random generator of the game.
+pub(crate) fn choose_position<R>(
+    strategy: LetterPlacement,
+    grid: &Grid,
+    letter: LetterIndex,
+    drop_column: Option<GridIndex>,
+    search: &WordSearch,
+    rng: &mut R,
+) -> Option<Position>
+where
+    R: Rng + ?Sized,
+{
+    let empty_cells: Vec<Position> = grid.empty_cells().copied().collect();
+    if empty_cells.is_empty() {
+        return None;
+    }
+
+    let candidates = match strategy {
+        LetterPlacement::Random => empty_cells,
+        LetterPlacement::ColumnDrop => return drop_in_column(grid, drop_column, rng),
+        LetterPlacement::NextToLetters => next_to_letters(grid, empty_cells),
+        LetterPlacement::EdgesFirst => closest_to_edges(grid, empty_cells),
+        LetterPlacement::AvoidWords => without_words(grid, letter, search, empty_cells),
+    };
+
+    choose(candidates, rng)
+}
+
+/// Picks a random position, in an order independent of the cells storage so that the same rng
+/// gives the same position.
+fn choose<R>(mut positions: Vec<Position>, rng: &mut R) -> Option<Position>
+where
+    R: Rng + ?Sized,
+{
+    positions.sort();
+    positions.choose(rng).copied()
+}
+
+/// Drops the letter from the top of the chosen column, or of a random column if it is full.
+///
+/// Falls back to a random empty cell when every column is full from the top.
+fn drop_in_column<R>(grid: &Grid, drop_column: Option<GridIndex>, rng: &mut R) -> Option<Position>
+where
+    R: Rng + ?Sized,
+{
+    // the letter enters the column at its first cell of the board
+    let landing = |x: GridIndex| {
+        (0..grid.height())
+            .map(|y| Position::new(x, y))
+            .skip_while(|pos| !grid.cells().contains_key(pos))
+            .take_while(|pos| grid.cells().get(pos) == Some(&Cell::Empty))
+            .last()
+    };
+
+    if let Some(pos) = drop_column.filter(|&x| x < grid.width()).and_then(landing) {
+        return Some(pos);
+    }
+
+    let landings: Vec<Position> = (0..grid.width()).filter_map(landing).collect();
+    if landings.is_empty() {
+        return choose(grid.empty_cells().copied().collect(), rng);
+    }
+
+    choose(landings, rng)
+}
+
+/// Keeps the empty cells next to a letter, all of them if the grid has no letter.
+fn next_to_letters(grid: &Grid, empty_cells: Vec<Position>) -> Vec<Position> {
+    let next_to_letters: Vec<Position> = empty_cells
+        .iter()
+        .filter(|pos| {
+            grid.neighbours(pos)
+                .iter()
+                .any(|next| matches!(grid.cell(next), Cell::Letter(_)))
+        })
+        .copied()
+        .collect();
+
+    if next_to_letters.is_empty() {
+        empty_cells
+    } else {
+        next_to_letters
+    }
+}
+
+/// Keeps the empty cells closest to an edge of the board, be it the border of the grid or a cell
+/// removed from the board.
+fn closest_to_edges(grid: &Grid, empty_cells: Vec<Position>) -> Vec<Position> {
+    let distances = distances_to_edges(grid);
+    // a board without edges, such as a torus, has every cell as close to them
+    let distance = |pos: &Position| distances.get(pos).copied().unwrap_or(u32::MAX);
+
+    let Some(closest) = empty_cells.iter().map(distance).min() else {
+        return empty_cells;
+    };
+
+    empty_cells
+        .into_iter()
+        .filter(|pos| distance(pos) == closest)
+        .collect()
+}
+
+/// Number of steps from each cell of the board to the closest cell on an edge.
+fn distances_to_edges(grid: &Grid) -> HashMap<Position, u32> {
+    let mut distances: HashMap<Position, u32> = grid
+        .cells()
+        .keys()
+        .filter(|pos| grid.is_on_edge(pos))
+        .map(|pos| (*pos, 0))
+        .collect();
+
+    let mut queue: VecDeque<Position> = distances.keys().copied().collect();
+    while let Some(pos) = queue.pop_front() {
+        let distance = distances[&pos] + 1;
+        for next in grid.neighbours(&pos) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                entry.insert(distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Keeps the empty cells where the letter does not complete a word, all of them if it completes
+/// a word everywhere.
+fn without_words(
+    grid: &Grid,
+    letter: LetterIndex,
+    search: &WordSearch,
+    empty_cells: Vec<Position>,
+) -> Vec<Position> {
+    let mut attempt = grid.clone();
+    let without_words: Vec<Position> = empty_cells
+        .iter()
+        .filter(|pos| {
+            attempt.update_cell(**pos, Cell::Letter(letter));
+            let has_word = attempt.has_word_at(pos, search);
+            attempt.update_cell(**pos, Cell::Empty);
+            !has_word
+        })
+        .copied()
+        .collect();
+
+    if without_words.is_empty() {
+        empty_cells
+    } else {
+        without_words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::{grid, grid::tests::classic_search, lexicon::FRENCH_LETTERS_TABLE};
+
+    fn place(
+        strategy: LetterPlacement,
+        grid: &Grid,
+        letter: &str,
+        drop_column: Option<u8>,
+    ) -> Vec<Position> {
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES").unwrap();
+        let search = classic_search(&golden_word);
+        let letter = FRENCH_LETTERS_TABLE.try_get_letter_index(letter).unwrap();
+
+        // every position chosen with a few seeds
+        (0..16u64)
+            .filter_map(|seed| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                choose_position(strategy, grid, letter, drop_column, &search, &mut rng)
+            })
+            .unique()
+            .sorted()
+            .collect()
+    }
+
+    #[test]
+    fn letters_are_dropped_on_top_of_columns() {
+        let grid = grid!(
+            [' ', 'A', ' ']
+            [' ', 'B', ' ']
+            [' ', 'C', 'D']
+        )
+        .unwrap();
+
+        assert_eq!(
+            place(LetterPlacement::ColumnDrop, &grid, "E", Some(2)),
+            vec![Position::new(2, 1)]
+        );
+        // the chosen column is full
+        assert_eq!(
+            place(LetterPlacement::ColumnDrop, &grid, "E", Some(1)),
+            vec![Position::new(0, 2), Position::new(2, 1)]
+        );
+    }
+
+    #[test]
+    fn letters_are_placed_on_edges_first() {
+        let grid = grid!(
+            ['A', 'B', 'C']
+            [' ', ' ', 'D']
+            ['E', 'F', 'G']
+        )
+        .unwrap();
+
+        assert_eq!(
+            place(LetterPlacement::EdgesFirst, &grid, "H", None),
+            vec![Position::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn letters_are_placed_next_to_holes_of_the_board() {
+        let mut grid = grid!(
+            ['A', 'A', 'A', 'A', 'A', 'A', 'A']
+            ['A', 'A', 'A', 'A', 'A', 'A', 'A']
+            ['A', 'A', ' ', ' ', 'A', 'A', 'A']
+            ['A', 'A', 'A', 'A', 'A', 'A', 'A']
+            ['A', 'A', 'A', 'A', 'A', 'A', 'A']
+            ['A', 'A', 'A', 'A', 'A', 'A', 'A']
+            ['A', 'A', 'A', 'A', 'A', 'A', 'A']
+        )
+        .unwrap();
+        grid.apply_layout("\n\n\n...x");
+
+        assert_eq!(
+            place(LetterPlacement::EdgesFirst, &grid, "H", None),
+            vec![Position::new(3, 2)]
+        );
+    }
+
+    #[test]
+    fn letters_avoid_completing_words() {
+        let grid = grid!(
+            ['M', 'A', 'I', 'S', 'O', ' ']
+            ['A', 'B', 'C', 'D', 'E', 'F']
+            ['A', 'B', 'C', 'D', 'E', 'F']
+            ['A', 'B', 'C', 'D', 'E', 'F']
+            ['A', 'B', 'C', 'D', 'E', 'F']
+            ['A', 'B', 'C', 'D', 'E', ' ']
+        )
+        .unwrap();
+
+        assert_eq!(
+            place(LetterPlacement::Random, &grid, "N", None),
+            vec![Position::new(5, 0), Position::new(5, 5)]
+        );
+        assert_eq!(
+            place(LetterPlacement::AvoidWords, &grid, "N", None),
+            vec![Position::new(5, 5)]
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use wasm_bindgen::prelude::*;
+
+use crate::lexicon::{LettersTable, LexiconError, Word};
+
+/// Rule used to score the words found in the grid.
+pub trait ScoringRule {
+    /// Scores a word, detailing how the score is computed.
+    ///
+    /// `bonuses` holds the bonuses of each letter, in reading order, it is empty when the word
+    /// is not read in the grid.
+    fn score(
+        &self,
+        word: &Word,
+        bonuses: &[LetterBonus],
+        letters_table: &LettersTable,
+        golden_word: &Word,
+    ) -> Result<ScoreBreakdown, LexiconError>;
+}
+
+/// Bonuses of a letter of a word read in the grid.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LetterBonus {
+    /// Premium of the cell under the letter.
+    pub premium: Option<Premium>,
+    /// Whether the letter is a wildcard tile.
+    pub wildcard: bool,
+    /// Whether the letter is a golden tile.
+    pub golden: bool,
+}
+
+/// Premium of a cell of the board, multiplying the score of the letter on it or of the words
+/// covering it.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Premium {
+    DoubleLetter,
+    TripleLetter,
+    DoubleWord,
+    TripleWord,
+}
+
+impl Premium {
+    /// Multiplier of the score of the letter on the cell.
+    pub fn letter_multiplier(&self) -> u32 {
+        match self {
+            Premium::DoubleLetter => 2,
+            Premium::TripleLetter => 3,
+            Premium::DoubleWord | Premium::TripleWord => 1,
+        }
+    }
+
+    /// Multiplier of the score of the words covering the cell.
+    pub fn word_multiplier(&self) -> u32 {
+        match self {
+            Premium::DoubleWord => 2,
+            Premium::TripleWord => 3,
+            Premium::DoubleLetter | Premium::TripleLetter => 1,
+        }
+    }
+}
+
+/// Details of the score of a word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    /// Score of each letter of the word, in reading order, letter premiums included.
+    pub letter_scores: Vec<u32>,
+    /// Multiplier applied by a premium to each letter of the word, in reading order.
+    pub letter_multipliers: Vec<u32>,
+    /// Multiplier applied to the sum of the letter scores, based on the word length.
+    pub length_multiplier: u32,
+    /// Multiplier applied by the word premiums and the golden tiles of the word.
+    pub word_multiplier: u32,
+    /// Bonus added when the word is the golden word.
+    pub golden_bonus: u32,
+    /// Bonus added for the letters shared with crossing words.
+    pub crossing_bonus: u32,
+    /// Percentage of the score kept because the word is read backwards, 100 otherwise.
+    pub reversed_percent: u32,
+    /// Percentage of the score kept because the word is not straight, 100 otherwise.
+    pub bent_percent: u32,
+    /// Final score of the word.
+    pub total: u32,
+}
+
+impl ScoreBreakdown {
+    /// Keeps the given percentage of the score of a word read backwards.
+    pub(crate) fn apply_reversed_percent(&mut self, percent: u32) {
+        self.reversed_percent = percent;
+        self.total = percentage_of(self.total, percent);
+    }
+
+    /// Keeps the given percentage of the score of a word that is not straight.
+    pub(crate) fn apply_bent_percent(&mut self, percent: u32) {
+        self.bent_percent = percent;
+        self.total = percentage_of(self.total, percent);
+    }
+}
+
+/// Scoring of the classic game.
+///
+/// The letter scores are summed and multiplied by 1, 2, 3 or 4 for words of 5, 6, 7 or 8
+/// letters, then by the word premiums and doubled by each golden tile. Letter premiums multiply
+/// the score of their letter, wildcard tiles are worth nothing. The golden word earns a flat
+/// bonus.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClassicScoring;
+
+impl ClassicScoring {
+    pub const GOLDEN_WORD_BONUS: u32 = 100;
+    pub const GOLDEN_TILE_MULTIPLIER: u32 = 2;
+}
+
+impl ScoringRule for ClassicScoring {
+    fn score(
+        &self,
+        word: &Word,
+        bonuses: &[LetterBonus],
+        letters_table: &LettersTable,
+        golden_word: &Word,
+    ) -> Result<ScoreBreakdown, LexiconError> {
+        let letter_multipliers: Vec<u32> = (0..word.length())
+            .map(|i| bonuses.get(i).copied().unwrap_or_default())
+            .map(|bonus| match bonus.premium {
+                _ if bonus.wildcard => 0,
+                Some(premium) => premium.letter_multiplier(),
+                None => 1,
+            })
+            .collect();
+        let word_multiplier = bonuses
+            .iter()
+            .flat_map(|bonus| {
+                let premium = bonus.premium.map_or(1, |p| p.word_multiplier());
+                let golden = if bonus.golden {
+                    Self::GOLDEN_TILE_MULTIPLIER
+                } else {
+                    1
+                };
+                [premium, golden]
+            })
+            .try_fold(1u32, |multiplier, m| multiplier.checked_mul(m))
+            .ok_or(LexiconError::ScoreOverflow)?;
+
+        let letter_scores = letters_table
+            .letter_scores(word)?
+            .into_iter()
+            .zip(letter_multipliers.iter())
+            .map(|(score, multiplier)| score.checked_mul(*multiplier))
+            .collect::<Option<Vec<u32>>>()
+            .ok_or(LexiconError::ScoreOverflow)?;
+
+        let length_multiplier: u32 = match word.length() {
+            5 => 1,
+            6 => 2,
+            7 => 3,
+            8 => 4,
+            _ => {
+                return Err(LexiconError::MissingScoreMultiplier { len: word.length() });
+            }
+        };
+
+        let golden_bonus = if word == golden_word {
+            Self::GOLDEN_WORD_BONUS
+        } else {
+            0
+        };
+
+        let total = checked_sum(letter_scores.iter().copied())
+            .and_then(|sum| sum.checked_mul(length_multiplier))
+            .and_then(|score| score.checked_mul(word_multiplier))
+            .and_then(|score| score.checked_add(golden_bonus))
+            .ok_or(LexiconError::ScoreOverflow)?;
+
+        Ok(ScoreBreakdown {
+            letter_scores,
+            letter_multipliers,
+            length_multiplier,
+            word_multiplier,
+            golden_bonus,
+            crossing_bonus: 0,
+            reversed_percent: 100,
+            bent_percent: 100,
+            total,
+        })
+    }
+}
+
+/// Applies a percentage to a score, saturating on overflow.
+pub(crate) fn percentage_of(score: u32, percent: u32) -> u32 {
+    (score as u64 * percent as u64 / 100).min(u32::MAX as u64) as u32
+}
+
+/// Sums scores, returns `None` on overflow.
+pub(crate) fn checked_sum(scores: impl IntoIterator<Item = u32>) -> Option<u32> {
+    scores
+        .into_iter()
+        .try_fold(0u32, |sum, score| sum.checked_add(score))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::lexicon::{FRENCH_LETTERS_TABLE, LetterConfig, NUMBER_OF_LETTERS};
+
+    #[test]
+    fn classic_scoring_details_the_word_score() -> Result<(), LexiconError> {
+        let word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES")?;
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("NUDITE")?;
+
+        let breakdown = ClassicScoring.score(&word, &[], &FRENCH_LETTERS_TABLE, &golden_word)?;
+        assert_eq!(breakdown.letter_scores, vec![9, 1, 7, 2, 1, 2]);
+        assert_eq!(breakdown.length_multiplier, 2);
+        assert_eq!(breakdown.golden_bonus, 0);
+        assert_eq!(breakdown.total, 44);
+
+        let breakdown = ClassicScoring.score(&word, &[], &FRENCH_LETTERS_TABLE, &word)?;
+        assert_eq!(breakdown.golden_bonus, ClassicScoring::GOLDEN_WORD_BONUS);
+        assert_eq!(breakdown.total, 144);
+
+        Ok(())
+    }
+
+    #[test]
+    fn classic_scoring_applies_the_premiums() -> Result<(), LexiconError> {
+        let word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES")?;
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("NUDITE")?;
+        let premiums = [
+            Some(Premium::TripleLetter),
+            None,
+            Some(Premium::DoubleWord),
+            None,
+            Some(Premium::DoubleLetter),
+            None,
+        ]
+        .map(|premium| LetterBonus {
+            premium,
+            ..LetterBonus::default()
+        });
+
+        let breakdown =
+            ClassicScoring.score(&word, &premiums, &FRENCH_LETTERS_TABLE, &golden_word)?;
+        assert_eq!(breakdown.letter_scores, vec![27, 1, 7, 2, 2, 2]);
+        assert_eq!(breakdown.letter_multipliers, vec![3, 1, 1, 1, 2, 1]);
+        assert_eq!(breakdown.word_multiplier, 2);
+        assert_eq!(breakdown.total, 41 * 2 * 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn classic_scoring_applies_the_special_tiles() -> Result<(), LexiconError> {
+        let word = FRENCH_LETTERS_TABLE.parse_word("ZEBRES")?;
+        let golden_word = FRENCH_LETTERS_TABLE.parse_word("NUDITE")?;
+        let mut bonuses = [LetterBonus::default(); 6];
+        bonuses[0].wildcard = true;
+        bonuses[1].golden = true;
+
+        let breakdown =
+            ClassicScoring.score(&word, &bonuses, &FRENCH_LETTERS_TABLE, &golden_word)?;
+        assert_eq!(breakdown.letter_scores, vec![0, 1, 7, 2, 1, 2]);
+        assert_eq!(breakdown.word_multiplier, 2);
+        assert_eq!(breakdown.total, 13 * 2 * 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn score_overflow_is_reported() -> Result<(), LexiconError> {
+        let table = LettersTable::new(
+            vec![LetterConfig {
+                letter: "A".to_string(),
+                repartition: NUMBER_OF_LETTERS,
+                score: u32::MAX / 4,
+            }],
+            NUMBER_OF_LETTERS,
+        )?;
+        let word = table.parse_word("AAAAA")?;
+
+        assert_eq!(table.score_word(&word), Err(LexiconError::ScoreOverflow));
+        assert_eq!(
+            ClassicScoring.score(&word, &[], &table, &word),
+            Err(LexiconError::ScoreOverflow)
+        );
+
+        Ok(())
+    }
+}
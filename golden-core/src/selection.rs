@@ -0,0 +1,174 @@
+use crate::grid::Match;
+
+/// Above this number of conflicting matches, the optimal selection falls back to the greedy one.
+const MAX_MATCHES_FOR_EXACT_SELECTION: usize = 32;
+
+/// Keeps the matches with the highest scores first, skipping any match that is not compatible
+/// with a previously kept match.
+///
+/// Matches must be sorted by decreasing score.
+pub(crate) fn select_greedy(matches: Vec<Match>, crossword: bool) -> Vec<Match> {
+    let mut selected: Vec<Match> = Vec::new();
+
+    for m in matches {
+        if selected
+            .iter()
+            .all(|kept| m.is_compatible_with(kept, crossword))
+        {
+            selected.push(m);
+        }
+    }
+
+    selected
+}
+
+/// Keeps the set of compatible matches that maximizes the total score.
+///
+/// Matches must be sorted by decreasing score, in case of equality the first matches are
+/// preferred.
+pub(crate) fn select_optimal(matches: Vec<Match>, crossword: bool) -> Vec<Match> {
+    let conflicts: Vec<Vec<usize>> = matches
+        .iter()
+        .enumerate()
+        .map(|(i, m1)| {
+            matches
+                .iter()
+                .enumerate()
+                .filter(|(j, m2)| i != *j && !m1.is_compatible_with(m2, crossword))
+                .map(|(j, _)| j)
+                .collect()
+        })
+        .collect();
+
+    let mut keep = vec![false; matches.len()];
+
+    for component in conflicting_components(&conflicts) {
+        let selected = if component.len() > MAX_MATCHES_FOR_EXACT_SELECTION {
+            greedy_indices(&component, &conflicts)
+        } else {
+            best_indices(&component, &conflicts, &matches)
+        };
+
+        for i in selected {
+            keep[i] = true;
+        }
+    }
+
+    matches
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(m, keep)| keep.then_some(m))
+        .collect()
+}
+
+/// Groups the matches that directly or indirectly share letters, indices are kept sorted.
+fn conflicting_components(conflicts: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut visited = vec![false; conflicts.len()];
+    let mut components = Vec::new();
+
+    for root in 0..conflicts.len() {
+        if visited[root] {
+            continue;
+        }
+
+        visited[root] = true;
+        let mut component = vec![];
+        let mut to_visit = vec![root];
+
+        while let Some(i) = to_visit.pop() {
+            component.push(i);
+            for &j in &conflicts[i] {
+                if !visited[j] {
+                    visited[j] = true;
+                    to_visit.push(j);
+                }
+            }
+        }
+
+        component.sort();
+        components.push(component);
+    }
+
+    components
+}
+
+fn greedy_indices(component: &[usize], conflicts: &[Vec<usize>]) -> Vec<usize> {
+    let mut selected: Vec<usize> = vec![];
+
+    for &i in component {
+        if selected.iter().all(|j| !conflicts[i].contains(j)) {
+            selected.push(i);
+        }
+    }
+
+    selected
+}
+
+/// Exhaustive branch and bound search of the best selection within a component.
+fn best_indices(component: &[usize], conflicts: &[Vec<usize>], matches: &[Match]) -> Vec<usize> {
+    struct Search<'a> {
+        component: &'a [usize],
+        conflicts: &'a [Vec<usize>],
+        scores: Vec<u64>,
+        // sum of the scores of the matches from the given rank to the end of the component
+        remaining: Vec<u64>,
+        best: Vec<usize>,
+        best_score: u64,
+    }
+
+    impl Search<'_> {
+        fn explore(&mut self, rank: usize, selected: &mut Vec<usize>, score: u64) {
+            if score > self.best_score {
+                self.best_score = score;
+                self.best = selected.clone();
+            }
+
+            if rank == self.component.len() || score + self.remaining[rank] <= self.best_score {
+                return;
+            }
+
+            let i = self.component[rank];
+
+            if selected.iter().all(|j| !self.conflicts[i].contains(j)) {
+                selected.push(i);
+                self.explore(rank + 1, selected, score + self.scores[rank]);
+                selected.pop();
+            }
+
+            self.explore(rank + 1, selected, score);
+        }
+    }
+
+    let scores: Vec<u64> = component
+        .iter()
+        .map(|&i| matches[i].score() as u64)
+        .collect();
+    let remaining = scores
+        .iter()
+        .rev()
+        .scan(0u64, |sum, score| {
+            *sum += score;
+            Some(*sum)
+        })
+        .collect::<Vec<u64>>()
+        .into_iter()
+        .rev()
+        .chain(std::iter::once(0))
+        .collect();
+
+    // the greedy selection is the first candidate, it is only replaced by strictly better ones
+    let best = greedy_indices(component, conflicts);
+    let best_score = best.iter().map(|&i| matches[i].score() as u64).sum();
+
+    let mut search = Search {
+        component,
+        conflicts,
+        scores,
+        remaining,
+        best,
+        best_score,
+    };
+    search.explore(0, &mut vec![], 0);
+
+    search.best
+}
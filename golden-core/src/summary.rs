@@ -0,0 +1,101 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use wasm_bindgen::prelude::*;
+
+use crate::game::{EndReason, FoundWord};
+
+/// Summary of a finished game, used by the results screen.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct GameSummary {
+    /// Reason why the game ended.
+    pub reason: EndReason,
+    /// Final score of the player.
+    pub score: u32,
+    /// Number of moves done by the player.
+    pub moves: u32,
+    words_by_length: Vec<WordsOfLength>,
+    longest_word: Option<FoundWord>,
+    highest_scoring_word: Option<FoundWord>,
+    /// Whether the golden word has been found.
+    pub golden_word_found: bool,
+    /// Total play time in milliseconds.
+    pub play_time_ms: u32,
+    /// Number of triplets placed in the grid.
+    pub triplets_consumed: u32,
+}
+
+impl GameSummary {
+    pub(crate) fn new(
+        reason: EndReason,
+        score: u32,
+        moves: u32,
+        found_words: &[FoundWord],
+        play_time_ms: u32,
+        triplets_consumed: u32,
+    ) -> GameSummary {
+        let words_by_length = found_words
+            .iter()
+            .fold(BTreeMap::<usize, Vec<FoundWord>>::new(), |mut map, w| {
+                map.entry(w.length()).or_default().push(w.clone());
+                map
+            })
+            .into_iter()
+            .map(|(length, words)| WordsOfLength { length, words })
+            .collect();
+
+        // in case of equality, the first word found is kept
+        let longest_word = found_words
+            .iter()
+            .min_by_key(|w| Reverse(w.length()))
+            .cloned();
+        let highest_scoring_word = found_words
+            .iter()
+            .min_by_key(|w| Reverse(w.score()))
+            .cloned();
+
+        GameSummary {
+            reason,
+            score,
+            moves,
+            words_by_length,
+            longest_word,
+            highest_scoring_word,
+            golden_word_found: found_words.iter().any(|w| w.is_golden_word()),
+            play_time_ms,
+            triplets_consumed,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl GameSummary {
+    /// Found words grouped by length, shortest first.
+    pub fn words_by_length(&self) -> Vec<WordsOfLength> {
+        self.words_by_length.clone()
+    }
+
+    pub fn longest_word(&self) -> Option<FoundWord> {
+        self.longest_word.clone()
+    }
+
+    pub fn highest_scoring_word(&self) -> Option<FoundWord> {
+        self.highest_scoring_word.clone()
+    }
+}
+
+/// Found words sharing the same length.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct WordsOfLength {
+    /// Number of letters of the words.
+    pub length: usize,
+    words: Vec<FoundWord>,
+}
+
+#[wasm_bindgen]
+impl WordsOfLength {
+    pub fn words(&self) -> Vec<FoundWord> {
+        self.words.clone()
+    }
+}
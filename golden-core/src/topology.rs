@@ -0,0 +1,116 @@
+use crate::{
+    config::{MovementDirections, ReadingDirections},
+    grid::{Direction, Position},
+};
+
+/// Arrangement of the cells of a grid, giving the neighbours of each cell.
+///
+/// Cells are always indexed by a column and a row, the topology tells which directions lead to
+/// a neighbour cell.
+pub trait Topology {
+    /// Directions leading to the cells sharing a side with a cell.
+    fn neighbour_directions(&self) -> Vec<Direction>;
+
+    /// Directions in which letters can move from one cell to another.
+    fn movement_directions(&self, movement: MovementDirections) -> Vec<Direction>;
+
+    /// Directions in which words can be read.
+    fn reading_directions(&self, reading: ReadingDirections) -> Vec<Direction>;
+
+    /// Centre of a cell once drawn, in cell widths from the top left corner of the board.
+    fn center(&self, pos: &Position) -> (f32, f32);
+
+    /// Whether reading in this direction goes against the natural reading order, that is
+    /// from right to left or from bottom to top.
+    fn is_reversed(&self, dir: Direction) -> bool {
+        let (dx, dy) = dir.offset();
+        dx < 0 || dy < 0
+    }
+}
+
+/// Square cells, each with four sides and four corners.
+pub struct SquareTopology;
+
+impl Topology for SquareTopology {
+    fn neighbour_directions(&self) -> Vec<Direction> {
+        Direction::orthogonal()
+    }
+
+    fn movement_directions(&self, movement: MovementDirections) -> Vec<Direction> {
+        match movement {
+            MovementDirections::Orthogonal => Direction::orthogonal(),
+            MovementDirections::All => Direction::all(),
+        }
+    }
+
+    fn reading_directions(&self, reading: ReadingDirections) -> Vec<Direction> {
+        match reading {
+            ReadingDirections::Forward => vec![Direction::E, Direction::S],
+            ReadingDirections::Orthogonal => Direction::orthogonal(),
+            ReadingDirections::All => Direction::all(),
+        }
+    }
+
+    fn center(&self, pos: &Position) -> (f32, f32) {
+        (pos.x as f32 + 0.5, pos.y as f32 + 0.5)
+    }
+}
+
+/// Hexagonal cells in axial coordinates, the column and the row being two of the three axes of
+/// the board.
+///
+/// The six neighbours are along the columns (`N` and `S`), along the rows (`E` and `O`) and
+/// along the third axis (`NE` and `SO`), drawn with pointy-top hexagons where rows are shifted
+/// by half a cell.
+pub struct HexTopology;
+
+impl HexTopology {
+    /// Distance between the centres of two rows, in cell widths.
+    const ROW_SPACING: f32 = 0.866_025_4;
+    /// Distance from the centre of a cell to its top corner, in cell widths.
+    const CORNER_RADIUS: f32 = 0.577_350_3;
+
+    /// Directions reading an axis from left to right.
+    fn forward_directions() -> Vec<Direction> {
+        vec![Direction::E, Direction::S, Direction::NE]
+    }
+}
+
+impl Topology for HexTopology {
+    fn neighbour_directions(&self) -> Vec<Direction> {
+        vec![
+            Direction::N,
+            Direction::NE,
+            Direction::E,
+            Direction::S,
+            Direction::SO,
+            Direction::O,
+        ]
+    }
+
+    /// Every neighbour shares a side, there is no diagonal.
+    fn movement_directions(&self, _movement: MovementDirections) -> Vec<Direction> {
+        self.neighbour_directions()
+    }
+
+    /// The three axes are read in both ways unless reading forward, there is no diagonal.
+    fn reading_directions(&self, reading: ReadingDirections) -> Vec<Direction> {
+        match reading {
+            ReadingDirections::Forward => HexTopology::forward_directions(),
+            ReadingDirections::Orthogonal | ReadingDirections::All => self.neighbour_directions(),
+        }
+    }
+
+    /// Each row is shifted by half a cell to the right of the one above.
+    fn center(&self, pos: &Position) -> (f32, f32) {
+        (
+            pos.x as f32 + pos.y as f32 / 2.0 + 0.5,
+            pos.y as f32 * HexTopology::ROW_SPACING + HexTopology::CORNER_RADIUS,
+        )
+    }
+
+    /// Each axis is read forward from left to right, `NE` included.
+    fn is_reversed(&self, dir: Direction) -> bool {
+        !HexTopology::forward_directions().contains(&dir)
+    }
+}
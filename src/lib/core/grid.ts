@@ -2,8 +2,10 @@ import { Cell as WasmCell } from '$lib/wasm/golden_core';
 
 export function transformToGrid(raw: WasmCell[]): Grid {
 	return raw.reduce<Grid>((cells: Grid, rawCell) => {
-		const x = rawCell.position.x;
-		const y = rawCell.position.y;
+		// display cells where they would be once the previewed move is done
+		const position = rawCell.preview_position ?? rawCell.position;
+		const x = position.x;
+		const y = position.y;
 
 		if (!cells[x]) {
 			cells[x] = {};
@@ -34,7 +34,7 @@ export function createGameState(wasmGame: WasmGame) {
 
 		// Update all state modules
 		clock.update(snapshot.clock_remaining_ms);
-		grid.update(transformToGrid(snapshot.grid()));
+		grid.update(transformToGrid(snapshot.grid()), snapshot.board_mask());
 		score.update(wasmGame.score);
 		triplets.updateIndex(wasmGame.triplets_current_index);
 		foundWords.update(snapshot.found_words());
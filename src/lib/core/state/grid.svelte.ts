@@ -2,13 +2,18 @@ import type { Grid } from '$lib/core/grid';
 
 export function createGridState() {
 	let grid = $state<Grid | null>(null);
+	let mask = $state<Uint8Array>(new Uint8Array());
 
 	return {
 		get grid() {
 			return grid;
 		},
-		update(newGrid: Grid | null) {
+		get mask() {
+			return mask;
+		},
+		update(newGrid: Grid | null, newMask: Uint8Array) {
 			grid = newGrid;
+			mask = newMask;
 		}
 	};
 }
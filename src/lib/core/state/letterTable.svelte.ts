@@ -1,11 +1,17 @@
 import { getContext, setContext } from 'svelte';
-import { is_empty_cell, LettersTable } from '$lib/wasm/golden_core';
+import {
+	is_empty_cell,
+	LettersTable,
+	tile_kind,
+	tile_letter,
+	TileKind
+} from '$lib/wasm/golden_core';
 
 const LETTERS_TABLE_CONTEXT_KEY = Symbol('letters_table_context');
 
 export interface LettersTableContext {
-	getLetter: (index: number) => string;
-	getScore: (index: number) => number;
+	getLetter: (index: number) => string | undefined;
+	getScore: (index: number) => number | undefined;
 }
 
 export function initLettersTableContext(lettersTable: LettersTable) {
@@ -20,8 +26,22 @@ export function getLetter(index: number): Letter | null {
 		return null;
 	}
 
+	const kind = tile_kind(index);
+	switch (kind) {
+		case TileKind.Wildcard:
+			return { index, kind, char: '?', score: 0 };
+		case TileKind.Bomb:
+			return { index, kind, char: '*', score: 0 };
+	}
+
 	const context = getContext<LettersTableContext>(LETTERS_TABLE_CONTEXT_KEY);
-	return { index, char: context.getLetter(index), score: context.getScore(index) };
+	const letter = tile_letter(index);
+	return {
+		index: letter,
+		kind,
+		char: context.getLetter(letter) ?? '',
+		score: context.getScore(letter) ?? 0
+	};
 }
 
 export function getWord(letters: number[]): Letter[] {
@@ -36,6 +56,7 @@ export function getWord(letters: number[]): Letter[] {
 
 export interface Letter {
 	index: number;
+	kind: TileKind;
 	char: string;
 	score: number;
 }